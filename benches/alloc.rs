@@ -0,0 +1,70 @@
+//! Allocations and time per read on a 1024-dim × 50-frame field: the
+//! allocating reads next to `read_region_into`, `read_region_slice` and
+//! `read_window_iter`. A counting global allocator prints the allocations
+//! made by 100 calls before each timing.
+//!
+//! Run with `cargo bench --bench alloc`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use temporal_field::{FieldConfig, Signal, TemporalField};
+
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Bench `read`, first printing how many allocations 100 calls make.
+fn bench_read(c: &mut Criterion, name: &str, mut read: impl FnMut()) {
+    const CALLS: usize = 100;
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..CALLS {
+        read();
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    println!("{name}: {allocations} allocations in {CALLS} calls");
+    c.bench_function(name, |b| b.iter(&mut read));
+}
+
+fn reads(c: &mut Criterion) {
+    let mut field = TemporalField::new(FieldConfig::new(1024, 50, 242));
+    for _ in 0..50 {
+        field.write_region(&[Signal::positive_amplified(90, 1); 1024], 0..1024);
+        field.advance();
+    }
+    let field = &field;
+
+    bench_read(c, "read_region 64", || {
+        black_box(field.read_region(black_box(128..192)));
+    });
+    let mut out = Vec::new();
+    bench_read(c, "read_region_into 64", || {
+        field.read_region_into(black_box(128..192), &mut out);
+    });
+    bench_read(c, "read_region_slice 64", || {
+        black_box(field.read_region_slice(black_box(128..192)));
+    });
+    bench_read(c, "read_window 10", || {
+        black_box(field.read_window(black_box(10)));
+    });
+    bench_read(c, "read_window_iter 10", || {
+        black_box(field.read_window_iter(black_box(10)).count());
+    });
+}
+
+criterion_group!(benches, reads);
+criterion_main!(benches);
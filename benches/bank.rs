@@ -0,0 +1,96 @@
+//! Fields of 32 dims × 8 frames, one monitored region each, a write to
+//! every tenth field per tick: a `FieldBank` with one bank observer against
+//! a `Vec<TemporalField>` with an observer per field, at 200 and at 20,000
+//! fields.
+//!
+//! Run with `cargo bench --bench bank`. Criterion estimates over two runs
+//! (x86_64, one core):
+//!
+//! ```text
+//!                       200 fields   20,000 fields
+//! FieldBank tick_all    68-74 µs     9.1-10.0 ms
+//! Vec<TemporalField>    72-73 µs     10.1-11.3 ms
+//! ```
+//!
+//! The bank costs nothing over ticking the fields by hand; its fields are
+//! plain `TemporalField`s. Laying every field's frames out in one shared
+//! slab was tried and measured no faster: decay and the region checks
+//! dominate, and even 20,000 fields of this size fit in cache.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use temporal_field::{
+    BankEvent, FieldBank, FieldConfig, FnObserver, MonitoredRegion, Signal, TemporalField,
+};
+
+const DIMS: usize = 32;
+
+fn config() -> FieldConfig {
+    FieldConfig::new(DIMS, 8, 230)
+}
+
+fn region() -> MonitoredRegion {
+    MonitoredRegion::new("concept", 0..DIMS, 50_000)
+}
+
+fn bank_vs_vec(c: &mut Criterion, count: usize) {
+    let signals = [Signal::positive_amplified(60, 1); DIMS];
+    let events = Arc::new(AtomicUsize::new(0));
+    let mut group = c.benchmark_group(format!("{count} fields"));
+    if count > 1000 {
+        group.sample_size(20);
+    }
+
+    let mut bank = FieldBank::new(config(), count);
+    for i in 0..count {
+        bank.field_mut(i).monitor_region(region()).unwrap();
+    }
+    let counter = events.clone();
+    bank.subscribe(Arc::new(move |_: &BankEvent| {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }));
+    let mut step = 0;
+    group.bench_function("FieldBank tick_all", |b| {
+        b.iter(|| {
+            for i in (step % 10..count).step_by(10) {
+                bank.write_region(i, &signals, 0..DIMS);
+            }
+            bank.tick_all();
+            step += 1;
+        })
+    });
+
+    let mut fields: Vec<TemporalField> = (0..count)
+        .map(|_| {
+            let mut field = TemporalField::new(config());
+            field.monitor_region(region()).unwrap();
+            let counter = events.clone();
+            field.subscribe(Arc::new(FnObserver(move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            })));
+            field
+        })
+        .collect();
+    let mut step = 0;
+    group.bench_function("Vec<TemporalField> tick", |b| {
+        b.iter(|| {
+            for i in (step % 10..count).step_by(10) {
+                fields[i].write_region(&signals, 0..DIMS);
+            }
+            for field in &mut fields {
+                field.tick();
+            }
+            step += 1;
+        })
+    });
+    group.finish();
+}
+
+fn bank(c: &mut Criterion) {
+    bank_vs_vec(c, 200);
+    bank_vs_vec(c, 20_000);
+}
+
+criterion_group!(benches, bank);
+criterion_main!(benches);
@@ -0,0 +1,45 @@
+//! Cost of cloning a 4096-dim × 100-frame field (~1.2 MB of signals) for a
+//! snapshot, and of the tick that follows while the snapshot is alive.
+//!
+//! Frames are shared copy-on-write, so the clone itself only bumps a
+//! reference count per frame; the first mutation of a shared frame pays
+//! for its copy. Eager decay touches every frame on the next tick; lazy
+//! decay only the current one.
+//!
+//! Run with `cargo bench --bench clone`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use temporal_field::{FieldConfig, Signal, TemporalField};
+
+const DIMS: usize = 4096;
+const FRAMES: usize = 100;
+
+fn field(lazy: bool) -> TemporalField {
+    let config = FieldConfig::new(DIMS, FRAMES, 250).with_lazy_decay(lazy);
+    let mut field = TemporalField::new(config);
+    for _ in 0..FRAMES {
+        field.write_region(&[Signal::positive_amplified(100, 1); DIMS], 0..DIMS);
+        field.advance();
+    }
+    field
+}
+
+fn clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("clone, 100 frames");
+    let original = field(false);
+    group.bench_function("clone", |b| b.iter(|| black_box(original.clone())));
+    for (name, lazy) in [("clone + tick, eager", false), ("clone + tick, lazy", true)] {
+        let mut field = field(lazy);
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let snapshot = field.clone();
+                field.tick();
+                black_box(snapshot)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, clone);
+criterion_main!(benches);
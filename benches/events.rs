@@ -0,0 +1,53 @@
+//! Event delivery throughput with 8 subscribers.
+//!
+//! Each iteration activates 16 monitored regions at once (16 RegionActive
+//! and a Convergence listing all 16) and clears them again (16 RegionQuiet
+//! and a ConvergenceEnded). Reported throughput is events fired per second;
+//! every event reaches all 8 subscribers.
+//!
+//! Run with `cargo bench --bench events`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use temporal_field::{FieldConfig, FnObserver, MonitoredRegion, Signal, TemporalField};
+
+const DIMS: usize = 256;
+const REGIONS: usize = 16;
+const SUBSCRIBERS: usize = 8;
+
+fn events(c: &mut Criterion) {
+    let mut field = TemporalField::new(FieldConfig::new(DIMS, 10, 242));
+    let width = DIMS / REGIONS;
+    for r in 0..REGIONS {
+        let region = MonitoredRegion::new(format!("r{r}"), r * width..(r + 1) * width, 10_000);
+        field.monitor_region(region).unwrap();
+    }
+    field.set_convergence_threshold(REGIONS);
+
+    let delivered = Arc::new(AtomicU64::new(0));
+    for _ in 0..SUBSCRIBERS {
+        let delivered = delivered.clone();
+        field.subscribe(Arc::new(FnObserver(move |_| {
+            delivered.fetch_add(1, Ordering::Relaxed);
+        })));
+    }
+
+    let signals = [Signal::positive_amplified(100, 1); DIMS];
+    let zeros = [Signal::ZERO; DIMS];
+    let cycle = |field: &mut TemporalField| {
+        field.write_region(black_box(&signals), 0..DIMS);
+        field.set_region(&zeros, 0..DIMS);
+    };
+    cycle(&mut field);
+    let fired = delivered.swap(0, Ordering::Relaxed) / SUBSCRIBERS as u64;
+    assert_eq!(fired, 2 * REGIONS as u64 + 2);
+
+    let mut group = c.benchmark_group("events");
+    group.throughput(Throughput::Elements(fired));
+    group.bench_function("8 subscribers", |b| b.iter(|| cycle(&mut field)));
+    group.finish();
+}
+
+criterion_group!(benches, events);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+//! A mostly-idle 4096-dim × 64-frame field with two monitored regions and
+//! no observers: an 8-dim burst every 16 frames, a tick and an advance per
+//! frame, and the whole-field activity reads.
+//!
+//! Run with `cargo bench --bench idle`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use temporal_field::{FieldConfig, MonitoredRegion, Signal, TemporalField};
+
+const DIMS: usize = 4096;
+const FRAMES: usize = 64;
+
+fn idle_field() -> TemporalField {
+    let mut field = TemporalField::new(FieldConfig::new(DIMS, FRAMES, 200));
+    field
+        .monitor_region(MonitoredRegion::new("low", 0..2048, 10_000))
+        .unwrap();
+    field
+        .monitor_region(MonitoredRegion::new("high", 2048..4096, 10_000))
+        .unwrap();
+    field
+}
+
+fn idle(c: &mut Criterion) {
+    let mut field = idle_field();
+    let mut frame = 0usize;
+    c.bench_function("idle frame (burst every 16)", |b| {
+        b.iter(|| {
+            if frame.is_multiple_of(16) {
+                let at = frame * 8 % DIMS;
+                field.write_region(&[Signal::positive_amplified(200, 1); 8], at..at + 8);
+            }
+            field.tick();
+            field.advance();
+            frame += 1;
+        })
+    });
+
+    let mut field = idle_field();
+    for frame in 0..FRAMES {
+        if frame.is_multiple_of(16) {
+            field.write_region(&[Signal::positive_amplified(200, 1); 8], 0..8);
+        }
+        field.advance();
+    }
+    c.bench_function("total_activity", |b| {
+        b.iter(|| black_box(&field).total_activity())
+    });
+    c.bench_function("max_magnitude", |b| {
+        b.iter(|| black_box(&field).max_magnitude())
+    });
+}
+
+criterion_group!(benches, idle);
+criterion_main!(benches);
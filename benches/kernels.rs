@@ -0,0 +1,58 @@
+//! Decay, scale and energy on a 4096-dim vector, and one tick of a
+//! 4096-dim × 100-frame field.
+//!
+//! Run once without and once with the `simd` feature; criterion reports
+//! the change against the previous run:
+//!
+//! ```text
+//! cargo bench --bench kernels
+//! cargo bench --bench kernels --features simd
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use temporal_field::{FieldConfig, FieldVector, Signal, TemporalField};
+
+const DIMS: usize = 4096;
+
+/// Mixed polarities and magnitudes, all within one multiplier.
+fn vector() -> FieldVector {
+    let signals = (0..DIMS)
+        .map(|i| match i % 3 {
+            0 => Signal::positive_amplified((i % 251) as u8, 1),
+            1 => Signal::negative_amplified((i % 199) as u8, 1),
+            _ => Signal::ZERO,
+        })
+        .collect();
+    FieldVector::from_signals(signals)
+}
+
+fn kernels(c: &mut Criterion) {
+    let v = vector();
+    c.bench_function("decay 4096", |b| {
+        b.iter_batched_ref(
+            || v.clone(),
+            |v| v.decay(black_box(242)),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+    c.bench_function("scale 4096", |b| {
+        b.iter_batched_ref(
+            || v.clone(),
+            |v| v.scale(black_box(200)),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+    c.bench_function("range_energy 4096", |b| {
+        b.iter(|| black_box(&v).range_energy(0..DIMS))
+    });
+
+    let mut field = TemporalField::new(FieldConfig::new(DIMS, 100, 255));
+    for _ in 0..100 {
+        field.write_full(&v);
+        field.advance();
+    }
+    c.bench_function("tick 4096x100", |b| b.iter(|| field.tick()));
+}
+
+criterion_group!(benches, kernels);
+criterion_main!(benches);
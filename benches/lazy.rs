@@ -0,0 +1,36 @@
+//! Eager vs lazy decay on a 1024-dim × 200-frame field where only the
+//! current frame is touched: each iteration writes 8 dims, ticks and reads
+//! the current frame. Eager decay walks all 200 frames per tick; lazy
+//! decay only the current one.
+//!
+//! Run with `cargo bench --bench lazy`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use temporal_field::{FieldConfig, Signal, TemporalField};
+
+const DIMS: usize = 1024;
+const FRAMES: usize = 200;
+
+fn eager_vs_lazy(c: &mut Criterion) {
+    let signals = [Signal::positive_amplified(40, 1); 8];
+    let mut group = c.benchmark_group("tick + read_current, 200 frames");
+    for (name, lazy) in [("eager", false), ("lazy", true)] {
+        let config = FieldConfig::new(DIMS, FRAMES, 250).with_lazy_decay(lazy);
+        let mut field = TemporalField::new(config);
+        for _ in 0..FRAMES {
+            field.write_region(&[Signal::positive_amplified(100, 1); DIMS], 0..DIMS);
+            field.advance();
+        }
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                field.write_region(black_box(&signals), 0..8);
+                field.tick();
+                black_box(field.read_current().get_current(0))
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, eager_vs_lazy);
+criterion_main!(benches);
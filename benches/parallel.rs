@@ -0,0 +1,40 @@
+//! Serial vs rayon-parallel decay: one tick of fields from 1024 × 64 up to
+//! 16384 × 256 signals, decayed on the calling thread and across threads.
+//! The small sizes show the hand-off overhead that
+//! `parallel_threshold_cells` avoids; the speedup on large ones scales
+//! with available cores.
+//!
+//! Run with `cargo bench --bench parallel --features rayon`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use temporal_field::{FieldConfig, FieldVector, Signal, TemporalField};
+
+fn field(dims: usize, frames: usize, threshold: usize) -> TemporalField {
+    let config = FieldConfig::new(dims, frames, 242).with_parallel_threshold_cells(threshold);
+    let mut field = TemporalField::new(config);
+    let v = FieldVector::from_signals(
+        (0..dims)
+            .map(|i| Signal::from_current((i % 509) as i32 - 254))
+            .collect(),
+    );
+    for _ in 0..frames {
+        field.write_full(&v);
+        field.advance();
+    }
+    field
+}
+
+fn serial_vs_parallel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tick");
+    for (dims, frames) in [(1024, 64), (4096, 64), (4096, 256), (16384, 256)] {
+        let size = format!("{dims}x{frames}");
+        for (name, threshold) in [("serial", usize::MAX), ("parallel", 0)] {
+            let mut field = field(dims, frames, threshold);
+            group.bench_function(BenchmarkId::new(name, &size), |b| b.iter(|| field.tick()));
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, serial_vs_parallel);
+criterion_main!(benches);
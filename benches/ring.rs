@@ -0,0 +1,37 @@
+//! Ring index math: `read_window(64)` over a 64-frame field, whose
+//! power-of-two count wraps with a mask, beside a 63-frame field that
+//! wraps with modulo. Frames are 16 dims so the indexing dominates.
+//!
+//! Run with `cargo bench --bench ring`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use temporal_field::{FieldConfig, Signal, TemporalField};
+
+const DIMS: usize = 16;
+
+fn filled(frames: usize) -> TemporalField {
+    let mut field = TemporalField::new(FieldConfig::new(DIMS, frames, 255));
+    for f in 0..frames * 2 + 5 {
+        field.write_region(
+            &[Signal::positive_amplified((f % 200) as u8 + 1, 1); DIMS],
+            0..DIMS,
+        );
+        field.advance();
+    }
+    field
+}
+
+fn window_reads(c: &mut Criterion) {
+    for frames in [64, 63] {
+        let field = filled(frames);
+        c.bench_function(&format!("read_window 64 of {frames} frames"), |b| {
+            b.iter(|| field.read_window(black_box(64)).len())
+        });
+        c.bench_function(&format!("energy_history 64 of {frames} frames"), |b| {
+            b.iter(|| field.energy_history(black_box(0..DIMS), 64))
+        });
+    }
+}
+
+criterion_group!(benches, window_reads);
+criterion_main!(benches);
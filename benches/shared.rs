@@ -0,0 +1,75 @@
+//! Four threads each writing 50 times to their own 64 dims of a 256-dim
+//! field, then one tick: a `SharedField` with a writer per thread against
+//! a `Mutex<TemporalField>` locked for every write.
+//!
+//! Both spawn their threads inside the timed loop. Run with
+//! `cargo bench --bench shared`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::sync::Mutex;
+use std::thread;
+use temporal_field::{FieldConfig, MonitoredRegion, SharedField, Signal, TemporalField};
+
+const THREADS: usize = 4;
+const WIDTH: usize = 64;
+const WRITES: usize = 50;
+
+fn field() -> TemporalField {
+    let mut field = TemporalField::new(FieldConfig::new(THREADS * WIDTH, 8, 230));
+    for i in 0..THREADS {
+        field
+            .monitor_region(MonitoredRegion::new(
+                format!("part {}", i),
+                i * WIDTH..(i + 1) * WIDTH,
+                50_000,
+            ))
+            .unwrap();
+    }
+    field
+}
+
+fn shared(c: &mut Criterion) {
+    let signals = [Signal::positive_amplified(3, 1); WIDTH];
+
+    let shared = SharedField::new(field());
+    let writers: Vec<_> = (0..THREADS)
+        .map(|i| shared.writer(i * WIDTH..(i + 1) * WIDTH).unwrap())
+        .collect();
+    c.bench_function("SharedField writers", |b| {
+        b.iter(|| {
+            thread::scope(|s| {
+                for writer in &writers {
+                    s.spawn(|| {
+                        for _ in 0..WRITES {
+                            writer.write(&signals);
+                        }
+                    });
+                }
+            });
+            shared.tick();
+        })
+    });
+
+    let naive = Mutex::new(field());
+    c.bench_function("Mutex<TemporalField> writes", |b| {
+        b.iter(|| {
+            thread::scope(|s| {
+                for i in 0..THREADS {
+                    let naive = &naive;
+                    s.spawn(move || {
+                        for _ in 0..WRITES {
+                            naive
+                                .lock()
+                                .unwrap()
+                                .write_region(&signals, i * WIDTH..(i + 1) * WIDTH);
+                        }
+                    });
+                }
+            });
+            naive.lock().unwrap().tick();
+        })
+    });
+}
+
+criterion_group!(benches, shared);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+//! Dense vs sparse frame storage on an 8192-dim × 50-frame field where
+//! under 2% of dims are non-zero: each iteration writes 128 dims, ticks and
+//! advances. Dense decay walks all 409,600 signals per tick; sparse decay
+//! walks the resident frame and the stored signals of the rest.
+//!
+//! Run with `cargo bench --bench sparse`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use temporal_field::{FieldConfig, FrameStorage, Signal, TemporalField};
+
+const DIMS: usize = 8192;
+const FRAMES: usize = 50;
+const ACTIVE: usize = 128;
+
+fn dense_vs_sparse(c: &mut Criterion) {
+    let signals = [Signal::positive_amplified(100, 1); ACTIVE];
+    let mut group = c.benchmark_group("write + tick + advance, 2% active");
+    for (name, storage) in [
+        ("dense", FrameStorage::Dense),
+        ("sparse", FrameStorage::Sparse),
+    ] {
+        let config = FieldConfig::new(DIMS, FRAMES, 250).with_frame_storage(storage);
+        let mut field = TemporalField::new(config);
+        let mut at = 0;
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                field.write_region(black_box(&signals), at..at + ACTIVE);
+                field.tick();
+                field.advance();
+                at = (at + ACTIVE) % DIMS;
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, dense_vs_sparse);
+criterion_main!(benches);
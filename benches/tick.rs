@@ -0,0 +1,42 @@
+//! Decay cost of `tick_n` vs `tick_by` on a 1024-dim field.
+//!
+//! Run with `cargo bench --bench tick`.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use temporal_field::{FieldConfig, Signal, TemporalField};
+
+const DIMS: usize = 1024;
+const FRAMES: usize = 50;
+const TICKS: usize = 64;
+
+fn field() -> TemporalField {
+    let mut field = TemporalField::new(FieldConfig::new(DIMS, FRAMES, 242));
+    for _ in 0..FRAMES {
+        field.write_region(&[Signal::positive_amplified(200, 1); DIMS], 0..DIMS);
+        field.advance();
+    }
+    field
+}
+
+fn tick_n_vs_tick_by(c: &mut Criterion) {
+    let template = field();
+    let mut group = c.benchmark_group("64 ticks");
+    group.bench_function("tick_n", |b| {
+        b.iter_batched_ref(
+            || template.clone(),
+            |field| field.tick_n(black_box(TICKS)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("tick_by", |b| {
+        b.iter_batched_ref(
+            || template.clone(),
+            |field| field.tick_by(black_box(TICKS as u64)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, tick_n_vs_tick_by);
+criterion_main!(benches);
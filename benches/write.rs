@@ -0,0 +1,64 @@
+//! Cost of a small `write_region` on a 4096-dim field.
+//!
+//! - `write_region 8 dims`: 16 monitored regions, cached region energies vs
+//!   a full rescan per write (the verify mode, which is what every write
+//!   cost before caching).
+//! - `write_region vs region count`: 64-dim regions with saturation and
+//!   density checks. Only the region the write overlaps is rescanned;
+//!   each other region adds a small constant, not a pass over its dims.
+//!
+//! Run with `cargo bench --bench write`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use temporal_field::{FieldConfig, MonitoredRegion, Signal, TemporalField};
+
+const DIMS: usize = 4096;
+
+fn field(
+    regions: usize,
+    width: usize,
+    build: impl Fn(MonitoredRegion) -> MonitoredRegion,
+) -> TemporalField {
+    let mut field = TemporalField::new(FieldConfig::new(DIMS, 10, 242));
+    for r in 0..regions {
+        let region =
+            MonitoredRegion::new(format!("r{r}"), r * width..(r + 1) * width, u64::MAX / 2);
+        field.monitor_region(build(region)).unwrap();
+    }
+    field.write_region(&[Signal::positive_amplified(50, 1); DIMS], 0..DIMS);
+    field
+}
+
+fn cached_vs_rescan(c: &mut Criterion) {
+    let signals = [Signal::positive_amplified(1, 1); 8];
+    let mut group = c.benchmark_group("write_region 8 dims");
+    for (name, verify) in [("cached", false), ("rescan", true)] {
+        let mut field = field(16, DIMS / 16, |r| r);
+        field.set_verify_energy_cache(verify);
+        let mut at = 0;
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                field.write_region(black_box(&signals), at..at + 8);
+                at = (at + 8) % DIMS;
+            })
+        });
+    }
+    group.finish();
+}
+
+fn region_count(c: &mut Criterion) {
+    let signals = [Signal::positive_amplified(1, 1); 8];
+    let mut group = c.benchmark_group("write_region vs region count");
+    for regions in [4, 16, 64] {
+        let mut field = field(regions, 64, |r| {
+            r.with_saturation_warning(900).with_density(900)
+        });
+        group.bench_with_input(BenchmarkId::from_parameter(regions), &regions, |b, _| {
+            b.iter(|| field.write_region(black_box(&signals), 0..8))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, cached_vs_rescan, region_count);
+criterion_main!(benches);
@@ -0,0 +1,450 @@
+//! Field bank - many small, identical fields ticked as one
+//!
+//! Architectures with a field per concept end up with hundreds of tiny
+//! fields. A [`FieldBank`] owns them side by side, built from one shared
+//! [`FieldConfig`], ticks them all with [`tick_all`](FieldBank::tick_all),
+//! and delivers their events to bank-level observers tagged with the index
+//! of the field that fired them.
+//!
+//! Each field in a bank is a plain [`TemporalField`]: triggers can differ
+//! per field, and a field behaves exactly as it would standalone.
+//!
+//! ```rust
+//! use temporal_field::{BankEvent, FieldBank, FieldConfig, MonitoredRegion, Signal};
+//! use std::sync::{Arc, Mutex};
+//!
+//! let mut bank = FieldBank::new(FieldConfig::new(32, 8, 230), 200);
+//! for i in 0..bank.len() {
+//!     bank.field_mut(i)
+//!         .monitor_region(MonitoredRegion::new("concept", 0..32, 100_000))
+//!         .unwrap();
+//! }
+//!
+//! let fired = Arc::new(Mutex::new(Vec::new()));
+//! let log = fired.clone();
+//! bank.subscribe(Arc::new(move |event: &BankEvent| log.lock().unwrap().push(event.field)));
+//!
+//! bank.write_region(42, &[Signal::positive_amplified(100, 1); 32], 0..32);
+//! bank.tick_all();
+//! assert_eq!(*fired.lock().unwrap(), vec![42]);
+//! ```
+
+use crate::config::FieldConfig;
+use crate::field::{FieldError, TemporalField};
+use crate::observer::{FieldEvent, FieldObserver, SubscriptionId, TriggerConfig};
+use crate::sync::Mutex;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+use ternary_signal::Signal;
+
+/// An event fired by one field of a [`FieldBank`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BankEvent {
+    /// Index of the field that fired the event
+    pub field: usize,
+    /// The event, as the field fired it
+    pub event: FieldEvent,
+}
+
+/// Observer that receives the events of every field in a bank.
+///
+/// Called synchronously from inside the bank's writes and ticks, like a
+/// [`FieldObserver`], so `on_event` must never block.
+pub trait BankObserver: Send + Sync {
+    /// Called when a field of the bank fires an event
+    fn on_event(&self, event: &BankEvent);
+}
+
+impl<F: Fn(&BankEvent) + Send + Sync> BankObserver for F {
+    fn on_event(&self, event: &BankEvent) {
+        self(event);
+    }
+}
+
+/// Bank-level observers, shared with every field's relay.
+type BankObservers = Arc<Mutex<Vec<(SubscriptionId, Arc<dyn BankObserver>)>>>;
+
+/// Field observer that tags a field's events with its index and hands them
+/// to the bank's observers.
+struct Relay {
+    field: usize,
+    observers: BankObservers,
+}
+
+impl FieldObserver for Relay {
+    fn on_event(&self, event: &FieldEvent) {
+        let observers = self.observers.lock();
+        let event = BankEvent {
+            field: self.field,
+            event: event.clone(),
+        };
+        for (_, observer) in observers.iter() {
+            observer.on_event(&event);
+        }
+    }
+}
+
+/// Check `count` fields of a config, together, against its
+/// `max_memory_bytes`, if any. A total past `usize::MAX` reports as
+/// `usize::MAX`.
+fn check_bank_memory(config: &FieldConfig, count: usize) -> Result<(), FieldError> {
+    let Some(limit) = config.max_memory_bytes else {
+        return Ok(());
+    };
+    let required = config.memory_bytes().saturating_mul(count);
+    if required > limit {
+        return Err(FieldError::MemoryLimitExceeded { required, limit });
+    }
+    Ok(())
+}
+
+/// N fields of one shape, ticked together.
+///
+/// Fields are addressed by index, `0..len()`. Bank observers are relayed
+/// through one subscription per field, attached while the bank has any
+/// observer; [`TemporalField::clear_observers`] on a field removes its
+/// relay too.
+pub struct FieldBank {
+    config: FieldConfig,
+    fields: Vec<TemporalField>,
+    observers: BankObservers,
+    /// Each field's relay subscription, while the bank has observers.
+    relays: Vec<SubscriptionId>,
+    next_subscription: u64,
+}
+
+impl FieldBank {
+    /// Create a bank of `count` fields built from `config`.
+    ///
+    /// # Panics
+    ///
+    /// If the config sets `max_memory_bytes` and the fields together would
+    /// exceed it.
+    pub fn new(config: FieldConfig, count: usize) -> Self {
+        if let Err(err) = check_bank_memory(&config, count) {
+            panic!("{}", err);
+        }
+        let fields = (0..count)
+            .map(|_| TemporalField::new(config.clone()))
+            .collect();
+        Self::from_fields(config, fields)
+    }
+
+    /// Create a bank of `count` fields, checking the config first (see
+    /// [`TemporalField::try_new`]). The `max_memory_bytes` cap applies to
+    /// all `count` fields together.
+    pub fn try_new(config: FieldConfig, count: usize) -> Result<Self, FieldError> {
+        check_bank_memory(&config, count)?;
+        let fields = (0..count)
+            .map(|_| TemporalField::try_new(config.clone()))
+            .collect::<Result<_, _>>()?;
+        Ok(Self::from_fields(config, fields))
+    }
+
+    /// Create a bank with one field per trigger set.
+    ///
+    /// Fails as [`TemporalField::apply_triggers`] for an inconsistent
+    /// trigger set.
+    ///
+    /// # Panics
+    ///
+    /// As [`new`](Self::new).
+    pub fn with_triggers(
+        config: FieldConfig,
+        triggers: Vec<TriggerConfig>,
+    ) -> Result<Self, FieldError> {
+        let mut bank = Self::new(config, triggers.len());
+        for (field, triggers) in bank.fields.iter_mut().zip(triggers) {
+            field.apply_triggers(triggers)?;
+        }
+        Ok(bank)
+    }
+
+    fn from_fields(config: FieldConfig, fields: Vec<TemporalField>) -> Self {
+        Self {
+            config,
+            fields,
+            observers: Arc::default(),
+            relays: Vec::new(),
+            next_subscription: 0,
+        }
+    }
+
+    /// Get the number of fields.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Check if the bank holds no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Get the config every field was built from.
+    pub fn config(&self) -> &FieldConfig {
+        &self.config
+    }
+
+    /// Get field `i`.
+    ///
+    /// # Panics
+    ///
+    /// If `i >= len()`.
+    pub fn field(&self, i: usize) -> &TemporalField {
+        &self.fields[i]
+    }
+
+    /// Get field `i` mutably, to configure it or use the full field API.
+    /// Its events still reach the bank's observers.
+    ///
+    /// # Panics
+    ///
+    /// If `i >= len()`.
+    pub fn field_mut(&mut self, i: usize) -> &mut TemporalField {
+        &mut self.fields[i]
+    }
+
+    /// Iterate over the fields in index order.
+    pub fn iter(&self) -> impl Iterator<Item = &TemporalField> + '_ {
+        self.fields.iter()
+    }
+
+    /// Replace field `i`'s triggers (see [`TemporalField::apply_triggers`]).
+    pub fn apply_triggers(&mut self, i: usize, triggers: TriggerConfig) -> Result<(), FieldError> {
+        self.fields[i].apply_triggers(triggers)
+    }
+
+    /// Write Signals to a region of field `i`'s current frame (additive) -
+    /// may fire events.
+    pub fn write_region(&mut self, i: usize, signals: &[Signal], range: Range<usize>) {
+        self.fields[i].write_region(signals, range);
+    }
+
+    /// Set Signals in a region of field `i`'s current frame (replace) - may
+    /// fire events.
+    pub fn set_region(&mut self, i: usize, signals: &[Signal], range: Range<usize>) {
+        self.fields[i].set_region(signals, range);
+    }
+
+    /// Advance every field by one tick, in index order.
+    ///
+    /// Each field ticks exactly as [`TemporalField::tick`] would; events
+    /// reach bank observers in field order.
+    pub fn tick_all(&mut self) {
+        for field in &mut self.fields {
+            field.tick();
+        }
+    }
+
+    /// Advance every field by `n` ticks in one step (see
+    /// [`TemporalField::tick_by`]).
+    pub fn tick_all_by(&mut self, n: u64) {
+        for field in &mut self.fields {
+            field.tick_by(n);
+        }
+    }
+
+    /// Move every field's write head onto its next frame (see
+    /// [`TemporalField::advance`]).
+    pub fn advance_all(&mut self) {
+        for field in &mut self.fields {
+            field.advance();
+        }
+    }
+
+    /// Subscribe an observer to the events of every field.
+    ///
+    /// Returns an id that can be passed to `unsubscribe()`.
+    pub fn subscribe(&mut self, observer: Arc<dyn BankObserver>) -> SubscriptionId {
+        let id = SubscriptionId(self.next_subscription);
+        self.next_subscription += 1;
+        self.observers.lock().push((id, observer));
+        if self.relays.is_empty() {
+            self.relays = (0..self.fields.len())
+                .map(|i| {
+                    let relay = Relay {
+                        field: i,
+                        observers: self.observers.clone(),
+                    };
+                    self.fields[i].subscribe(Arc::new(relay))
+                })
+                .collect();
+        }
+        id
+    }
+
+    /// Remove a bank observer. Returns false if the id was not subscribed.
+    ///
+    /// Once the last observer is gone the relays are detached, so fields
+    /// without observers of their own skip building events again.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let mut observers = self.observers.lock();
+        let Some(idx) = observers.iter().position(|(sub, _)| *sub == id) else {
+            return false;
+        };
+        observers.remove(idx);
+        if observers.is_empty() {
+            drop(observers);
+            for (field, relay) in self.fields.iter_mut().zip(self.relays.drain(..)) {
+                field.unsubscribe(relay);
+            }
+        }
+        true
+    }
+
+    /// Get the number of bank observers.
+    pub fn observer_count(&self) -> usize {
+        self.observers.lock().len()
+    }
+}
+
+impl fmt::Debug for FieldBank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldBank")
+            .field("fields", &self.fields.len())
+            .field("dims", &self.config.dims)
+            .field("frame_count", &self.config.frame_count)
+            .field("observers", &self.observer_count())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observer::{MonitoredRegion, RecordingObserver};
+    use alloc::vec;
+
+    /// Bank observer that keeps every event it sees.
+    #[derive(Default)]
+    struct BankRecorder(Mutex<Vec<BankEvent>>);
+
+    impl BankObserver for BankRecorder {
+        fn on_event(&self, event: &BankEvent) {
+            self.0.lock().push(event.clone());
+        }
+    }
+
+    fn triggers(threshold: u64) -> TriggerConfig {
+        TriggerConfig {
+            regions: vec![
+                MonitoredRegion::new("low", 0..16, threshold),
+                MonitoredRegion::new("high", 16..32, threshold),
+            ],
+            convergence_threshold: 2,
+            ..TriggerConfig::default()
+        }
+    }
+
+    /// The write, if any, that field `i` gets at `step`.
+    fn drive(step: usize, i: usize) -> Option<(Vec<Signal>, Range<usize>)> {
+        let at = (step * 7 + i * 5) % 32;
+        let magnitude = (40 + step * 13 + i * 29) % 256;
+        (step + i).is_multiple_of(3).then(|| {
+            let end = (at + 8).min(32);
+            let signals = vec![Signal::positive_amplified(magnitude as u8, 1); end - at];
+            (signals, at..end)
+        })
+    }
+
+    #[test]
+    fn test_bank_matches_standalone_fields() {
+        let config = FieldConfig::new(32, 8, 200);
+        let sets = vec![triggers(20_000), triggers(60_000), TriggerConfig::default()];
+        let mut bank = FieldBank::with_triggers(config.clone(), sets.clone()).unwrap();
+        let recorder = Arc::new(BankRecorder::default());
+        bank.subscribe(recorder.clone());
+
+        let mut solo: Vec<_> = sets
+            .into_iter()
+            .map(|triggers| {
+                let mut field = TemporalField::new(config.clone());
+                field.apply_triggers(triggers).unwrap();
+                let events = Arc::new(RecordingObserver::new());
+                field.subscribe(events.clone());
+                (field, events)
+            })
+            .collect();
+
+        for step in 0..40 {
+            for (i, (field, _)) in solo.iter_mut().enumerate() {
+                if let Some((signals, range)) = drive(step, i) {
+                    field.write_region(&signals, range.clone());
+                    bank.write_region(i, &signals, range);
+                }
+                match step % 10 {
+                    9 => field.tick_by(3),
+                    _ => field.tick(),
+                }
+                if step % 4 == 3 {
+                    field.advance();
+                }
+            }
+            match step % 10 {
+                9 => bank.tick_all_by(3),
+                _ => bank.tick_all(),
+            }
+            if step % 4 == 3 {
+                bank.advance_all();
+            }
+        }
+
+        let seen = recorder.0.lock();
+        for (i, (field, events)) in solo.iter().enumerate() {
+            let banked = bank.field(i);
+            assert_eq!(banked.tick_count(), field.tick_count());
+            assert_eq!(banked.read_window(8), field.read_window(8));
+            let relayed: Vec<_> = seen
+                .iter()
+                .filter(|e| e.field == i)
+                .map(|e| e.event.clone())
+                .collect();
+            let standalone: Vec<_> = events.events().into_iter().map(|(_, e)| e).collect();
+            assert_eq!(relayed, standalone);
+        }
+        assert!(seen.iter().any(|e| e.field == 0));
+    }
+
+    #[test]
+    fn test_bank_relays_attach_while_subscribed() {
+        let mut bank = FieldBank::new(FieldConfig::new(8, 4, 128), 3);
+        assert_eq!(bank.field(0).observer_count(), 0);
+
+        let first = bank.subscribe(Arc::new(|_: &BankEvent| {}));
+        let second = bank.subscribe(Arc::new(BankRecorder::default()));
+        assert_eq!(bank.observer_count(), 2);
+        assert!(bank.iter().all(|field| field.observer_count() == 1));
+
+        assert!(bank.unsubscribe(first));
+        assert!(!bank.unsubscribe(first));
+        assert_eq!(bank.field(2).observer_count(), 1);
+        assert!(bank.unsubscribe(second));
+        assert!(bank.iter().all(|field| field.observer_count() == 0));
+    }
+
+    #[test]
+    fn test_bank_memory_limit_covers_all_fields() {
+        let config = FieldConfig::new(32, 8, 200);
+        let per_field = config.memory_bytes();
+        let config = config.with_max_memory_bytes(per_field * 10);
+
+        assert!(FieldBank::try_new(config.clone(), 10).is_ok());
+        assert_eq!(
+            FieldBank::try_new(config.clone(), 11).err(),
+            Some(FieldError::MemoryLimitExceeded {
+                required: per_field * 11,
+                limit: per_field * 10
+            })
+        );
+        // The total saturates rather than wrapping back under the cap
+        assert!(matches!(
+            FieldBank::try_new(config, usize::MAX),
+            Err(FieldError::MemoryLimitExceeded {
+                required: usize::MAX,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,105 @@
+//! Clocks - wall time for catch-up ticking
+//!
+//! [`TemporalField::sync_to`](crate::TemporalField::sync_to) reads a
+//! [`Clock`] once per call and ticks the field by the time that passed.
+//! [`SystemClock`] reads real time; [`ManualClock`] only moves when told
+//! to, for deterministic tests and replays.
+
+/// Source of monotonic time in whole milliseconds.
+pub trait Clock {
+    /// Get the current time in milliseconds since the clock's own epoch.
+    ///
+    /// Must never go backwards.
+    fn now_ms(&self) -> u64;
+}
+
+/// Real time, from a monotonic `Instant` taken when the clock was created.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+pub struct SystemClock {
+    start: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl SystemClock {
+    /// Start a clock at 0 ms.
+    pub fn new() -> Self {
+        Self {
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u64 {
+        self.start.elapsed().as_millis() as u64
+    }
+}
+
+/// Clock that only moves when advanced.
+///
+/// ```rust
+/// use temporal_field::{Clock, ManualClock};
+///
+/// let mut clock = ManualClock::new();
+/// clock.advance(37);
+/// assert_eq!(clock.now_ms(), 37);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ManualClock {
+    now_ms: u64,
+}
+
+impl ManualClock {
+    /// Start a clock at 0 ms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the clock forward by `ms`.
+    pub fn advance(&mut self, ms: u64) {
+        self.now_ms = self.now_ms.saturating_add(ms);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_ms(&self) -> u64 {
+        self.now_ms
+    }
+}
+
+/// Where a field's last sync left off.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ClockSync {
+    /// Clock time of the last sync.
+    last_ms: u64,
+    /// Elapsed time not yet ticked, in ms × Hz (1000 = one tick).
+    carry: u64,
+}
+
+impl ClockSync {
+    pub(crate) fn new(now_ms: u64) -> Self {
+        Self {
+            last_ms: now_ms,
+            carry: 0,
+        }
+    }
+
+    /// Move to `now_ms` and return the whole ticks at `hz` that passed,
+    /// carrying the remainder to the next sync.
+    pub(crate) fn advance(&mut self, now_ms: u64, hz: u32) -> u64 {
+        let elapsed = now_ms.saturating_sub(self.last_ms);
+        self.last_ms = self.last_ms.max(now_ms);
+        let units = self.carry.saturating_add(elapsed.saturating_mul(hz as u64));
+        self.carry = units % 1000;
+        units / 1000
+    }
+}
@@ -0,0 +1,98 @@
+//! Deferred commands - let observers write back into the field
+//!
+//! Observers run inside `check_and_fire` while the field is mutably
+//! borrowed, so they cannot write to it directly. Instead they enqueue
+//! writes on a [`FieldCommands`] handle; the field applies them after the
+//! current event pass returns. Writes applied this way can fire further
+//! events, whose observers can enqueue further writes - a cascade. The
+//! field stops after a configurable depth so cascades always terminate.
+
+use crate::sync::Mutex;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::ops::Range;
+use ternary_signal::Signal;
+
+/// A write queued by an observer.
+#[derive(Clone, Debug)]
+pub(crate) enum FieldCommand {
+    /// Additive write to a region of the current frame
+    WriteRegion {
+        signals: Vec<Signal>,
+        range: Range<usize>,
+    },
+    /// Replace a region of the current frame
+    SetRegion {
+        signals: Vec<Signal>,
+        range: Range<usize>,
+    },
+}
+
+/// Cloneable handle for queueing writes from inside observers.
+///
+/// Get one from `TemporalField::commands()` and move it into the observer.
+///
+/// ```rust
+/// use temporal_field::{
+///     FieldConfig, FieldEvent, FnObserver, MonitoredRegion, Signal, TemporalField,
+/// };
+/// use std::sync::Arc;
+///
+/// let mut field = TemporalField::new(FieldConfig::new(96, 10, 242));
+/// field.monitor_region(MonitoredRegion::new("audio", 0..32, 50_000)).unwrap();
+/// field.monitor_region(MonitoredRegion::new("text", 32..64, 50_000)).unwrap();
+///
+/// // Binding detector writes a grounded-concept signal back into the field
+/// let commands = field.commands();
+/// field.subscribe(Arc::new(FnObserver(move |event| {
+///     if let FieldEvent::Convergence { .. } = event {
+///         commands.write_region(&[Signal::positive_amplified(200, 1); 32], 64..96);
+///     }
+/// })));
+///
+/// let signals = vec![Signal::positive_amplified(128, 1); 32];
+/// field.write_region(&signals, 0..32);
+/// field.write_region(&signals, 32..64);
+/// assert_eq!(field.read_region(64..65)[0].magnitude, 200);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FieldCommands {
+    queue: Arc<Mutex<Vec<FieldCommand>>>,
+}
+
+impl FieldCommands {
+    /// Queue an additive write to a region of the current frame.
+    pub fn write_region(&self, signals: &[Signal], range: Range<usize>) {
+        self.push(FieldCommand::WriteRegion {
+            signals: signals.to_vec(),
+            range,
+        });
+    }
+
+    /// Queue a replacing write to a region of the current frame.
+    pub fn set_region(&self, signals: &[Signal], range: Range<usize>) {
+        self.push(FieldCommand::SetRegion {
+            signals: signals.to_vec(),
+            range,
+        });
+    }
+
+    /// Get the number of queued commands.
+    pub fn len(&self) -> usize {
+        self.queue.lock().len()
+    }
+
+    /// Check if no commands are queued.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn push(&self, command: FieldCommand) {
+        self.queue.lock().push(command);
+    }
+
+    /// Take every queued command, leaving the queue empty.
+    pub(crate) fn take(&self) -> Vec<FieldCommand> {
+        core::mem::take(&mut *self.queue.lock())
+    }
+}
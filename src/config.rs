@@ -2,9 +2,97 @@
 //!
 //! ASTRO_004 compliant: No floats. Uses u8 for retention (255 = 1.0).
 
+use crate::field::{FIELD_BASE_BYTES, FRAME_OVERHEAD_BYTES};
+use ternary_signal::Signal;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// How frames lose magnitude each tick.
+///
+/// Retentions are u8 where 255 = 1.0. The field's current retention
+/// ([`TemporalField::retention`](crate::TemporalField::retention)) replaces
+/// the retention carried by Multiplicative and Floor, so
+/// `set_retention` and retention boosts compose with the mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DecayMode {
+    /// Multiply effective values by the retention (the classic decay)
+    Multiplicative(u8),
+    /// Move effective values this much toward zero, stopping at zero
+    Linear(u8),
+    /// Multiplicative decay, then zero any dim whose effective magnitude
+    /// fell below `min_magnitude`
+    Floor {
+        /// Retention applied first
+        retention: u8,
+        /// Smallest effective magnitude that survives
+        min_magnitude: u8,
+    },
+}
+
+impl Default for DecayMode {
+    fn default() -> Self {
+        DecayMode::Multiplicative(255)
+    }
+}
+
+impl DecayMode {
+    /// The retention this mode multiplies by, if any.
+    pub fn retention(&self) -> Option<u8> {
+        match *self {
+            DecayMode::Multiplicative(retention) | DecayMode::Floor { retention, .. } => {
+                Some(retention)
+            }
+            DecayMode::Linear(_) => None,
+        }
+    }
+
+    /// The same mode with its retention replaced (Linear is unchanged).
+    pub fn with_retention(self, retention: u8) -> Self {
+        match self {
+            DecayMode::Multiplicative(_) => DecayMode::Multiplicative(retention),
+            DecayMode::Floor { min_magnitude, .. } => DecayMode::Floor {
+                retention,
+                min_magnitude,
+            },
+            DecayMode::Linear(step) => DecayMode::Linear(step),
+        }
+    }
+}
+
+/// What the write head finds when [`advance`](crate::TemporalField::advance)
+/// moves it onto the next frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AdvanceMode {
+    /// Leave the slot as it is: the oldest frame, decayed (the classic ring)
+    #[default]
+    Keep,
+    /// Start from an empty frame
+    Clear,
+    /// Start from a copy of the frame being left, decayed by `retention`
+    /// (255 = 1.0), so the present persists into the next time step
+    CarryForward {
+        /// Retention applied to the carried copy
+        retention: u8,
+    },
+}
+
+/// How a field stores its frames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FrameStorage {
+    /// Every signal of every frame stored, one buffer per frame (shared
+    /// copy-on-write between clones)
+    #[default]
+    Dense,
+    /// Frames other than the one being written hold only their non-zero
+    /// signals ([`SparseFieldVector`](crate::SparseFieldVector)), so decay
+    /// costs O(non-zero) per frame. Reading such a frame unpacks a copy.
+    Sparse,
+}
+
 /// Configuration for a temporal field.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -21,6 +109,64 @@ pub struct FieldConfig {
 
     /// Tick rate in Hz (for time calculations).
     pub tick_rate_hz: u32,
+
+    /// Optional 2D view of the dims as (rows, cols), row-major.
+    /// Purely an addressing aid for rectangular regions.
+    pub grid: Option<(usize, usize)>,
+
+    /// How frames decay each tick. Its retention (if any) follows `retention`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub decay_mode: DecayMode,
+
+    /// What the next frame holds when the write head advances.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub advance_mode: AdvanceMode,
+
+    /// Advance the write head automatically every this many ticks
+    /// (None = only when `advance()` is called).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ticks_per_frame: Option<u32>,
+
+    /// Seed for dithered (stochastic) rounding in decay. None = truncating
+    /// decay. The same seed replays the same decay.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dither_seed: Option<u64>,
+
+    /// Optional ceiling on the current frame's effective magnitude.
+    /// After each write, a frame whose max exceeds it is scaled down to fit.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub auto_normalize: Option<u8>,
+
+    /// Defer decay of older frames until they are read or written.
+    ///
+    /// Ticks only decay the current frame; every other frame catches up on
+    /// `retention^Δticks` when touched. Results match eager decay to within
+    /// truncation rounding.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub lazy_decay: bool,
+
+    /// With the `rayon` feature, split each tick's decay across threads once
+    /// the field holds at least this many signals (`dims × frame_count`).
+    /// Smaller fields decay on the calling thread.
+    #[cfg_attr(feature = "serde", serde(default = "default_parallel_threshold_cells"))]
+    pub parallel_threshold_cells: usize,
+
+    /// Dense or sparse frame storage. Behavior is the same either way;
+    /// sparse wins when few dims are non-zero at a time.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub frame_storage: FrameStorage,
+
+    /// Refuse fields whose [`memory_bytes`](Self::memory_bytes) exceed this
+    /// (None = no cap). Checked by [`validate`](Self::validate) and at
+    /// construction.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_memory_bytes: Option<usize>,
+}
+
+/// Default [`FieldConfig::parallel_threshold_cells`]: 2¹⁸ signals, below
+/// which thread hand-off costs more than it saves.
+fn default_parallel_threshold_cells() -> usize {
+    1 << 18
 }
 
 impl FieldConfig {
@@ -32,12 +178,146 @@ impl FieldConfig {
             frame_count,
             retention,
             tick_rate_hz: 100,
+            grid: None,
+            decay_mode: DecayMode::Multiplicative(retention),
+            advance_mode: AdvanceMode::Keep,
+            ticks_per_frame: None,
+            dither_seed: None,
+            auto_normalize: None,
+            lazy_decay: false,
+            parallel_threshold_cells: default_parallel_threshold_cells(),
+            frame_storage: FrameStorage::Dense,
+            max_memory_bytes: None,
         }
     }
 
+    /// Address the dims as a `rows × cols` grid (row-major).
+    ///
+    /// `rows × cols` must equal `dims`; see [`validate`](Self::validate).
+    pub fn with_grid(mut self, rows: usize, cols: usize) -> Self {
+        self.grid = Some((rows, cols));
+        self
+    }
+
+    /// Decay with `mode` instead of plain multiplicative retention.
+    ///
+    /// A mode that carries a retention also sets `retention`.
+    ///
+    /// ```rust
+    /// use temporal_field::{DecayMode, FieldConfig};
+    ///
+    /// // Small residue is cleaned up instead of lingering as 2, 2, 1, 1...
+    /// let config = FieldConfig::new(64, 10, 242).with_decay_mode(DecayMode::Floor {
+    ///     retention: 242,
+    ///     min_magnitude: 4,
+    /// });
+    /// assert_eq!(config.retention, 242);
+    /// ```
+    pub fn with_decay_mode(mut self, mode: DecayMode) -> Self {
+        if let Some(retention) = mode.retention() {
+            self.retention = retention;
+        }
+        self.decay_mode = mode;
+        self
+    }
+
+    /// Set what the next frame holds when the write head advances.
+    pub fn with_advance_mode(mut self, mode: AdvanceMode) -> Self {
+        self.advance_mode = mode;
+        self
+    }
+
+    /// Advance the write head automatically every `ticks` ticks, so writers
+    /// only ever write "now".
+    ///
+    /// See [`TemporalField::tick`](crate::TemporalField::tick).
+    pub fn with_ticks_per_frame(mut self, ticks: u32) -> Self {
+        self.ticks_per_frame = Some(ticks);
+        self
+    }
+
+    /// Round decayed values stochastically, seeded with `seed`; see
+    /// [`FieldVector::decay_dithered`](crate::FieldVector::decay_dithered).
+    pub fn with_dither(mut self, seed: u64) -> Self {
+        self.dither_seed = Some(seed);
+        self
+    }
+
+    /// Scale the current frame down after every write so its max effective
+    /// magnitude stays at or below `target_max`.
+    ///
+    /// See [`TemporalField::normalize_global`](crate::TemporalField::normalize_global).
+    pub fn with_auto_normalize(mut self, target_max: u8) -> Self {
+        self.auto_normalize = Some(target_max);
+        self
+    }
+
+    /// Decay frames other than the current one lazily, when next accessed.
+    ///
+    /// Worth it for long windows where only recent frames are read. Deferred
+    /// frames catch up without dithering.
+    pub fn with_lazy_decay(mut self, lazy: bool) -> Self {
+        self.lazy_decay = lazy;
+        self
+    }
+
+    /// Decay across threads once the field holds at least `cells` signals
+    /// (needs the `rayon` feature). 0 always decays in parallel.
+    pub fn with_parallel_threshold_cells(mut self, cells: usize) -> Self {
+        self.parallel_threshold_cells = cells;
+        self
+    }
+
+    /// Store frames densely or sparsely; see [`FrameStorage`].
+    ///
+    /// Sparse storage never decays in parallel.
+    pub fn with_frame_storage(mut self, storage: FrameStorage) -> Self {
+        self.frame_storage = storage;
+        self
+    }
+
+    /// Cap the memory a field of this config may take; see
+    /// [`max_memory_bytes`](Self::max_memory_bytes).
+    ///
+    /// ```rust
+    /// use temporal_field::{FieldConfig, FieldError, TemporalField};
+    ///
+    /// // 1M dims × 10k frames would be ~30 GB of signals
+    /// let config = FieldConfig::new(1_000_000, 10_000, 242).with_max_memory_bytes(1 << 30);
+    /// assert!(matches!(
+    ///     TemporalField::try_new(config),
+    ///     Err(FieldError::MemoryLimitExceeded { .. })
+    /// ));
+    /// ```
+    pub fn with_max_memory_bytes(mut self, bytes: usize) -> Self {
+        self.max_memory_bytes = Some(bytes);
+        self
+    }
+
+    /// Bytes a field of this config takes up front with dense storage:
+    /// `dims × frame_count × 3` for the signals, plus per-frame and fixed
+    /// bookkeeping. Triggers and the event log add to it as they are
+    /// configured; see
+    /// [`TemporalField::memory_usage`](crate::TemporalField::memory_usage).
+    ///
+    /// Sparse storage starts smaller but can grow past this as frames fill.
+    /// Saturates at `usize::MAX`.
+    pub fn memory_bytes(&self) -> usize {
+        let per_frame = self
+            .dims
+            .saturating_mul(size_of::<Signal>())
+            .saturating_add(FRAME_OVERHEAD_BYTES);
+        self.frame_count
+            .saturating_mul(per_frame)
+            .saturating_add(FIELD_BASE_BYTES)
+    }
+
     /// Get temporal window duration in milliseconds.
+    ///
+    /// Each frame spans `ticks_per_frame` ticks when set, one otherwise.
     pub fn window_ms(&self) -> u32 {
-        (self.frame_count as u32 * 1000) / self.tick_rate_hz
+        let ticks = self.frame_count as u64 * self.ticks_per_frame.unwrap_or(1) as u64;
+        (ticks * 1000 / self.tick_rate_hz as u64).min(u32::MAX as u64) as u32
     }
 
     /// Validate configuration.
@@ -48,6 +328,20 @@ impl FieldConfig {
         if self.frame_count == 0 {
             return Err("frame_count must be > 0");
         }
+        if let Some((rows, cols)) = self.grid {
+            if rows.checked_mul(cols) != Some(self.dims) {
+                return Err("grid rows × cols must equal dims");
+            }
+        }
+        if self.ticks_per_frame == Some(0) {
+            return Err("ticks_per_frame must be > 0");
+        }
+        if self
+            .max_memory_bytes
+            .is_some_and(|max| self.memory_bytes() > max)
+        {
+            return Err("memory_bytes exceeds max_memory_bytes");
+        }
         // retention is u8, always valid (0-255)
         Ok(())
     }
@@ -70,6 +364,10 @@ mod tests {
         let config = FieldConfig::new(64, 50, 255);
         // 50 frames at 100Hz = 500ms
         assert_eq!(config.window_ms(), 500);
+
+        // 4 ticks per frame: 200 ticks
+        let config = config.with_ticks_per_frame(4);
+        assert_eq!(config.window_ms(), 2000);
     }
 
     #[test]
@@ -82,5 +380,31 @@ mod tests {
 
         let invalid_frames = FieldConfig::new(64, 0, 242);
         assert!(invalid_frames.validate().is_err());
+
+        let grid = FieldConfig::new(32, 10, 242).with_grid(4, 8);
+        assert!(grid.validate().is_ok());
+
+        let invalid_grid = FieldConfig::new(32, 10, 242).with_grid(4, 7);
+        assert!(invalid_grid.validate().is_err());
+
+        let invalid_ticks = FieldConfig::new(32, 10, 242).with_ticks_per_frame(0);
+        assert!(invalid_ticks.validate().is_err());
+
+        let bytes = FieldConfig::new(32, 10, 242).memory_bytes();
+        let capped = FieldConfig::new(32, 10, 242).with_max_memory_bytes(bytes);
+        assert!(capped.validate().is_ok());
+        let over_cap = FieldConfig::new(32, 10, 242).with_max_memory_bytes(bytes - 1);
+        assert!(over_cap.validate().is_err());
+    }
+
+    #[test]
+    fn test_memory_bytes() {
+        let small = FieldConfig::new(64, 10, 242).memory_bytes();
+        let wide = FieldConfig::new(128, 10, 242).memory_bytes();
+        assert_eq!(wide - small, 64 * 10 * 3);
+
+        // Absurd shapes saturate instead of overflowing
+        let huge = FieldConfig::new(usize::MAX / 2, 10_000, 242);
+        assert_eq!(huge.memory_bytes(), usize::MAX);
     }
 }
@@ -0,0 +1,66 @@
+//! Crossbeam observer - bounded, non-blocking event delivery
+//!
+//! For real-time pipelines (audio callbacks, control loops) where the std
+//! mpsc sender's allocation behavior is unwelcome. Sends with `try_send`
+//! and counts drops instead of ever blocking the field's write path.
+//!
+//! Requires the `crossbeam` feature.
+
+use crate::observer::{FieldEvent, FieldObserver};
+use crossbeam_channel::{Sender, TrySendError};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Channel-based observer for a bounded `crossbeam_channel`.
+///
+/// When the channel is full the new event is dropped and counted.
+pub struct CrossbeamObserver {
+    sender: Sender<FieldEvent>,
+    dropped: AtomicU64,
+}
+
+impl CrossbeamObserver {
+    /// Create an observer that forwards events to `sender`.
+    pub fn new(sender: Sender<FieldEvent>) -> Self {
+        Self {
+            sender,
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the number of events dropped because the channel was full.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl FieldObserver for CrossbeamObserver {
+    fn on_event(&self, event: &FieldEvent) {
+        if let Err(TrySendError::Full(_)) = self.sender.try_send(event.clone()) {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FieldConfig, TemporalField};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_burst_into_capacity_one() {
+        let mut field = TemporalField::new(FieldConfig::new(8, 10, 242));
+        field.set_emit_tick_events(true);
+
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        let observer = Arc::new(CrossbeamObserver::new(tx));
+        field.subscribe(observer.clone());
+
+        // Burst of 10 Tick events, nobody draining
+        field.tick_n(10);
+
+        assert_eq!(observer.dropped(), 9);
+        assert_eq!(rx.try_recv().map(|e| e.tick()), Ok(1));
+        assert!(rx.try_recv().is_err());
+    }
+}
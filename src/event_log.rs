@@ -0,0 +1,126 @@
+//! Event log - polling alternative to pub/sub
+//!
+//! Some hosts (game loops, fixed-step simulations) prefer to collect events
+//! once per frame instead of reacting inside callbacks. When enabled, the
+//! field records every fired event here alongside delivering it to
+//! observers. The log is a bounded ring: when full, the oldest event is
+//! evicted.
+
+use crate::observer::FieldEvent;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// Bounded log of `(tick, event)` pairs, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct EventLog {
+    events: VecDeque<(u64, FieldEvent)>,
+    capacity: usize,
+    evicted: u64,
+}
+
+impl EventLog {
+    /// Create a log holding at most `capacity` events (0 = disabled).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+            evicted: 0,
+        }
+    }
+
+    /// Check if the log records events.
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Get maximum number of events held.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Change capacity, evicting the oldest events if it shrinks.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict_to(capacity);
+    }
+
+    /// Record an event fired at `tick`.
+    pub fn record(&mut self, tick: u64, event: FieldEvent) {
+        if !self.is_enabled() {
+            return;
+        }
+        self.evict_to(self.capacity - 1);
+        self.events.push_back((tick, event));
+    }
+
+    /// Take all recorded events, oldest first.
+    pub fn drain(&mut self) -> Vec<(u64, FieldEvent)> {
+        self.events.drain(..).collect()
+    }
+
+    /// Iterate recorded events without removing them.
+    pub fn iter(&self) -> impl Iterator<Item = &(u64, FieldEvent)> {
+        self.events.iter()
+    }
+
+    /// Get number of recorded events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Check if no events are recorded.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Heap bytes held by the log's buffer (events at their inline size).
+    pub(crate) fn heap_bytes(&self) -> usize {
+        self.events.capacity() * size_of::<(u64, FieldEvent)>()
+    }
+
+    /// Get total events evicted because the log was full.
+    pub fn evicted(&self) -> u64 {
+        self.evicted
+    }
+
+    fn evict_to(&mut self, len: usize) {
+        while self.events.len() > len {
+            self.events.pop_front();
+            self.evicted += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn tick(tick: u64) -> FieldEvent {
+        FieldEvent::Tick {
+            tick,
+            total_energy: 0,
+        }
+    }
+
+    #[test]
+    fn test_bounded_evicts_oldest() {
+        let mut log = EventLog::new(3);
+        for t in 0..5 {
+            log.record(t, tick(t));
+        }
+        assert_eq!(log.len(), 3);
+        assert_eq!(log.evicted(), 2);
+        let ticks: Vec<u64> = log.drain().into_iter().map(|(t, _)| t).collect();
+        assert_eq!(ticks, vec![2, 3, 4]);
+        assert!(log.is_empty());
+    }
+
+    #[test]
+    fn test_disabled_records_nothing() {
+        let mut log = EventLog::new(0);
+        log.record(0, tick(0));
+        assert!(log.is_empty());
+    }
+}
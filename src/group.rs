@@ -0,0 +1,304 @@
+//! Field group - coupled fields ticked in lockstep
+//!
+//! A [`FieldGroup`] holds differently shaped fields under string keys
+//! (say "sensory" and "memory"), ticks them in registration order with
+//! [`tick_all`](FieldGroup::tick_all), and couples them with bridges: an
+//! event in one field becomes a write into another.
+//!
+//! Bridged writes are deferred. The event is mapped when it fires, but the
+//! write waits in the target's inbox until the start of the next
+//! `tick_all`, so a field never writes into another from inside its own
+//! event pass, and coupled fields can't feed back within one group tick.
+//!
+//! ```rust
+//! use temporal_field::{EventMask, FieldConfig, FieldGroup, MonitoredRegion, Signal, TemporalField};
+//!
+//! let mut sensory = TemporalField::new(FieldConfig::new(64, 10, 200));
+//! sensory.monitor_region(MonitoredRegion::new("edge", 0..32, 50_000)).unwrap();
+//! sensory.monitor_region(MonitoredRegion::new("motion", 32..64, 50_000)).unwrap();
+//!
+//! let mut group = FieldGroup::new();
+//! group.add("sensory", sensory).unwrap();
+//! group.add("memory", TemporalField::new(FieldConfig::new(8, 10, 255))).unwrap();
+//! group
+//!     .bridge("sensory", EventMask::CONVERGENCE, "memory", |_| {
+//!         Some((vec![Signal::positive_amplified(50, 1); 8], 0..8))
+//!     })
+//!     .unwrap();
+//!
+//! let sensory = group.field_mut("sensory").unwrap();
+//! sensory.write_region(&[Signal::positive_amplified(128, 1); 64], 0..64);
+//! assert_eq!(group.pending(), 1);
+//!
+//! group.tick_all();
+//! assert_eq!(group.field("memory").unwrap().read_current()[0].current(), 50);
+//! ```
+
+use crate::commands::{FieldCommand, FieldCommands};
+use crate::field::{FieldError, TemporalField};
+use crate::observer::{EventMask, FieldEvent, FieldObserver, SubscriptionId};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+use ternary_signal::Signal;
+
+/// Maps a source event to a write into the target, or None to skip it.
+type BridgeFn = dyn Fn(&FieldEvent) -> Option<(Vec<Signal>, Range<usize>)> + Send + Sync;
+
+/// Field observer that maps a source field's events into the target's
+/// inbox.
+struct Bridge {
+    map: Box<BridgeFn>,
+    inbox: FieldCommands,
+}
+
+impl FieldObserver for Bridge {
+    fn on_event(&self, event: &FieldEvent) {
+        if let Some((signals, range)) = (self.map)(event) {
+            self.inbox.write_region(&signals, range);
+        }
+    }
+}
+
+/// One field of a group with the bridged writes waiting for it.
+struct Member {
+    key: String,
+    field: TemporalField,
+    inbox: FieldCommands,
+}
+
+/// Differently shaped fields, ticked in lockstep and coupled by bridges.
+///
+/// Fields are addressed by key and tick in the order they were added.
+/// Each bridge is a subscription on its source field;
+/// [`TemporalField::unsubscribe`] with the id [`bridge`](Self::bridge)
+/// returns removes it.
+#[derive(Default)]
+pub struct FieldGroup {
+    members: Vec<Member>,
+}
+
+impl FieldGroup {
+    /// Create an empty group.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `field` under `key`, to tick after every field already added.
+    ///
+    /// Fails with [`FieldError::DuplicateField`] if the key is taken.
+    pub fn add(&mut self, key: impl Into<String>, field: TemporalField) -> Result<(), FieldError> {
+        let key = key.into();
+        if self.position(&key).is_ok() {
+            return Err(FieldError::DuplicateField(key));
+        }
+        self.members.push(Member {
+            key,
+            field,
+            inbox: FieldCommands::default(),
+        });
+        Ok(())
+    }
+
+    /// Get the number of fields.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Check if the group holds no fields.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Get the field under `key`.
+    pub fn field(&self, key: &str) -> Option<&TemporalField> {
+        let i = self.position(key).ok()?;
+        Some(&self.members[i].field)
+    }
+
+    /// Get the field under `key` mutably, to write to it or use the full
+    /// field API. Events it fires still cross its bridges.
+    pub fn field_mut(&mut self, key: &str) -> Option<&mut TemporalField> {
+        let i = self.position(key).ok()?;
+        Some(&mut self.members[i].field)
+    }
+
+    /// Iterate over the keys in tick order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> + '_ {
+        self.members.iter().map(|member| member.key.as_str())
+    }
+
+    /// Iterate over the keys and fields in tick order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &TemporalField)> + '_ {
+        self.members
+            .iter()
+            .map(|member| (member.key.as_str(), &member.field))
+    }
+
+    /// Couple two fields: every event of `from` selected by `filter` is
+    /// passed to `map`, and the `(signals, range)` it returns is written
+    /// (additively) into `to` at the start of the next
+    /// [`tick_all`](Self::tick_all).
+    ///
+    /// `map` runs inside the source field's event pass, like any observer,
+    /// so it must not block. Returning None skips the event. A field may
+    /// bridge to itself; the write still waits for the next group tick.
+    ///
+    /// Returns the bridge's subscription id on the source field.
+    pub fn bridge(
+        &mut self,
+        from: &str,
+        filter: EventMask,
+        to: &str,
+        map: impl Fn(&FieldEvent) -> Option<(Vec<Signal>, Range<usize>)> + Send + Sync + 'static,
+    ) -> Result<SubscriptionId, FieldError> {
+        let from = self.position(from)?;
+        let to = self.position(to)?;
+        let bridge = Bridge {
+            map: Box::new(map),
+            inbox: self.members[to].inbox.clone(),
+        };
+        Ok(self.members[from]
+            .field
+            .subscribe_filtered(Arc::new(bridge), filter))
+    }
+
+    /// Get the number of bridged writes waiting for the next group tick.
+    pub fn pending(&self) -> usize {
+        self.members.iter().map(|member| member.inbox.len()).sum()
+    }
+
+    /// Apply the bridged writes queued so far, then advance every field by
+    /// one tick in registration order.
+    ///
+    /// Each field's bridged writes land as one batch. Events fired during
+    /// this call - by the bridged writes or by the ticks - queue their
+    /// bridged writes for the next call.
+    pub fn tick_all(&mut self) {
+        let queued: Vec<_> = self
+            .members
+            .iter()
+            .map(|member| member.inbox.take())
+            .collect();
+        for (member, commands) in self.members.iter_mut().zip(queued) {
+            if commands.is_empty() {
+                continue;
+            }
+            member.field.batch(|field| {
+                for command in commands {
+                    match command {
+                        FieldCommand::WriteRegion { signals, range } => {
+                            field.write_region(&signals, range)
+                        }
+                        FieldCommand::SetRegion { signals, range } => {
+                            field.set_region(&signals, range)
+                        }
+                    }
+                }
+            });
+        }
+        for member in &mut self.members {
+            member.field.tick();
+        }
+    }
+
+    fn position(&self, key: &str) -> Result<usize, FieldError> {
+        self.members
+            .iter()
+            .position(|member| member.key == key)
+            .ok_or_else(|| FieldError::UnknownField(key.into()))
+    }
+}
+
+impl fmt::Debug for FieldGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldGroup")
+            .field("fields", &self.keys().collect::<Vec<_>>())
+            .field("pending", &self.pending())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::FieldConfig;
+    use crate::observer::MonitoredRegion;
+    use alloc::vec;
+
+    fn group() -> FieldGroup {
+        let mut a = TemporalField::new(FieldConfig::new(64, 10, 230));
+        a.monitor_region(MonitoredRegion::new("left", 0..32, 50_000))
+            .unwrap();
+        a.monitor_region(MonitoredRegion::new("right", 32..64, 50_000))
+            .unwrap();
+        let mut group = FieldGroup::new();
+        group.add("a", a).unwrap();
+        group
+            .add("b", TemporalField::new(FieldConfig::new(8, 4, 255)))
+            .unwrap();
+        group
+    }
+
+    fn b_level(group: &FieldGroup) -> i32 {
+        group.field("b").unwrap().read_current()[0].current()
+    }
+
+    #[test]
+    fn test_bridged_write_lands_on_next_group_tick() {
+        let mut group = group();
+        assert_eq!(
+            group.add("b", TemporalField::new(FieldConfig::new(8, 4, 255))),
+            Err(FieldError::DuplicateField("b".into()))
+        );
+        assert!(matches!(
+            group.bridge("a", EventMask::ALL, "c", |_| None),
+            Err(FieldError::UnknownField(key)) if key == "c"
+        ));
+        group
+            .bridge("a", EventMask::CONVERGENCE, "b", |_| {
+                Some((vec![Signal::positive_amplified(10, 1); 8], 0..8))
+            })
+            .unwrap();
+
+        // Converges inside the first group tick, before "b" ticks
+        let a = group.field_mut("a").unwrap();
+        a.schedule_write(1, vec![Signal::positive_amplified(128, 1); 64], 0..64);
+        group.tick_all();
+        assert_eq!(group.pending(), 1);
+        assert_eq!(b_level(&group), 0);
+
+        group.tick_all();
+        assert_eq!(group.pending(), 0);
+        assert_eq!(b_level(&group), 10);
+        assert_eq!(group.field("b").unwrap().tick_count(), 2);
+        assert_eq!(group.keys().collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_self_bridge_does_not_reenter() {
+        let mut group = group();
+        // Every write to "b" bridges another write into "b"
+        let id = group
+            .bridge("b", EventMask::ALL, "b", |_| {
+                Some((vec![Signal::positive_amplified(1, 1); 8], 0..8))
+            })
+            .unwrap();
+        group.field_mut("b").unwrap().set_emit_tick_events(true);
+
+        for tick in 1..=3 {
+            group.tick_all();
+            assert_eq!(group.pending(), 1);
+            assert_eq!(b_level(&group), tick - 1);
+        }
+
+        assert!(group.field_mut("b").unwrap().unsubscribe(id));
+        group.tick_all();
+        group.tick_all();
+        assert_eq!(group.pending(), 0);
+        assert_eq!(b_level(&group), 3);
+    }
+}
@@ -0,0 +1,333 @@
+//! Region layout - one declarative source of truth for region ranges
+//!
+//! Writers and readers usually live in different crates. Defining the same
+//! region constants in each of them is how ranges drift. A `RegionLayout`
+//! declares the regions once (name + width) and derives everything else:
+//! contiguous ranges, total dims, monitored regions, and the field config.
+//!
+//! ```rust
+//! use temporal_field::RegionLayout;
+//!
+//! let layout = RegionLayout::builder()
+//!     .region("audio", 64)
+//!     .region("text", 64)
+//!     .region("motor", 32)
+//!     .build()
+//!     .unwrap();
+//!
+//! assert_eq!(layout.range("text"), 64..128);
+//! assert_eq!(layout.total_dims(), 160);
+//! ```
+
+use crate::config::FieldConfig;
+use crate::observer::MonitoredRegion;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Error returned when a layout is invalid or does not match a field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LayoutError {
+    /// The layout's total dims differ from the field/snapshot dims.
+    DimsMismatch {
+        /// Total dims declared by the layout
+        layout: usize,
+        /// Dims of the field or snapshot being checked
+        field: usize,
+    },
+    /// A region does not start where the previous region ended.
+    NotContiguous {
+        /// Name of the offending region
+        name: String,
+        /// Where the region was expected to start
+        expected_start: usize,
+    },
+    /// Two regions share the same name.
+    DuplicateName(String),
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::DimsMismatch { layout, field } => {
+                write!(f, "layout declares {} dims but field has {}", layout, field)
+            }
+            LayoutError::NotContiguous {
+                name,
+                expected_start,
+            } => write!(f, "region '{}' does not start at {}", name, expected_start),
+            LayoutError::DuplicateName(name) => write!(f, "duplicate region name '{}'", name),
+        }
+    }
+}
+
+impl core::error::Error for LayoutError {}
+
+/// A named, contiguous range within a layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LayoutRegion {
+    /// Name for identification
+    pub name: String,
+    /// Dimension range
+    pub range: Range<usize>,
+}
+
+/// Static layout of named, contiguous, non-overlapping regions.
+///
+/// Built with [`RegionLayout::builder`]. Ranges are assigned in declaration
+/// order starting at 0, so region N+1 always starts where region N ends.
+///
+/// With the `serde` feature the layout can be saved alongside snapshots.
+/// Deserialization validates adjacency and names; use [`check_dims`] to
+/// compare a loaded layout against the snapshot it belongs to.
+///
+/// [`check_dims`]: RegionLayout::check_dims
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "Vec<LayoutRegion>", into = "Vec<LayoutRegion>")
+)]
+pub struct RegionLayout {
+    regions: Vec<LayoutRegion>,
+}
+
+/// Builder for [`RegionLayout`].
+#[derive(Clone, Debug, Default)]
+pub struct RegionLayoutBuilder {
+    regions: Vec<LayoutRegion>,
+    next_start: usize,
+}
+
+impl RegionLayoutBuilder {
+    /// Append a region of `width` dims directly after the previous one.
+    ///
+    /// Names must be unique; [`build`](Self::build) reports a repeat.
+    pub fn region(mut self, name: impl Into<String>, width: usize) -> Self {
+        let name = name.into();
+        let range = self.next_start..self.next_start + width;
+        self.next_start = range.end;
+        self.regions.push(LayoutRegion { name, range });
+        self
+    }
+
+    /// Finish the layout, or fail with [`LayoutError::DuplicateName`] if
+    /// two regions share a name.
+    pub fn build(self) -> Result<RegionLayout, LayoutError> {
+        RegionLayout::try_from(self.regions)
+    }
+}
+
+impl RegionLayout {
+    /// Start declaring a layout.
+    pub fn builder() -> RegionLayoutBuilder {
+        RegionLayoutBuilder::default()
+    }
+
+    /// Get the range of a named region.
+    ///
+    /// # Panics
+    /// Panics if no region has this name. Use [`get`](Self::get) for a fallible lookup.
+    pub fn range(&self, name: &str) -> Range<usize> {
+        self.get(name)
+            .unwrap_or_else(|| panic!("unknown region '{}'", name))
+    }
+
+    /// Get the range of a named region, if it exists.
+    pub fn get(&self, name: &str) -> Option<Range<usize>> {
+        self.regions
+            .iter()
+            .find(|r| r.name == name)
+            .map(|r| r.range.clone())
+    }
+
+    /// Get all regions in declaration order.
+    pub fn regions(&self) -> &[LayoutRegion] {
+        &self.regions
+    }
+
+    /// Get total dimensions covered by the layout.
+    pub fn total_dims(&self) -> usize {
+        self.regions.last().map(|r| r.range.end).unwrap_or(0)
+    }
+
+    /// Build monitored regions from `(name, threshold)` pairs.
+    ///
+    /// threshold: Energy threshold (sum of squared magnitudes), default hysteresis.
+    ///
+    /// # Panics
+    /// Panics if a name is not part of the layout.
+    pub fn monitored(&self, thresholds: &[(&str, u64)]) -> Vec<MonitoredRegion> {
+        thresholds
+            .iter()
+            .map(|&(name, threshold)| MonitoredRegion::new(name, self.range(name), threshold))
+            .collect()
+    }
+
+    /// Build a field configuration with dims set to [`total_dims`](Self::total_dims).
+    pub fn config(&self, frame_count: usize, retention: u8) -> FieldConfig {
+        FieldConfig::new(self.total_dims(), frame_count, retention)
+    }
+
+    /// Validate that regions are contiguous from 0 and uniquely named.
+    pub fn validate(&self) -> Result<(), LayoutError> {
+        let mut expected_start = 0;
+        for (i, region) in self.regions.iter().enumerate() {
+            if self.regions[..i].iter().any(|r| r.name == region.name) {
+                return Err(LayoutError::DuplicateName(region.name.clone()));
+            }
+            if region.range.start != expected_start || region.range.end < region.range.start {
+                return Err(LayoutError::NotContiguous {
+                    name: region.name.clone(),
+                    expected_start,
+                });
+            }
+            expected_start = region.range.end;
+        }
+        Ok(())
+    }
+
+    /// Check that the layout matches a field or snapshot with `dims` dimensions.
+    pub fn check_dims(&self, dims: usize) -> Result<(), LayoutError> {
+        if self.total_dims() != dims {
+            return Err(LayoutError::DimsMismatch {
+                layout: self.total_dims(),
+                field: dims,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check that the layout matches a field configuration.
+    pub fn check_config(&self, config: &FieldConfig) -> Result<(), LayoutError> {
+        self.check_dims(config.dims)
+    }
+}
+
+impl TryFrom<Vec<LayoutRegion>> for RegionLayout {
+    type Error = LayoutError;
+
+    fn try_from(regions: Vec<LayoutRegion>) -> Result<Self, Self::Error> {
+        let layout = RegionLayout { regions };
+        layout.validate()?;
+        Ok(layout)
+    }
+}
+
+impl From<RegionLayout> for Vec<LayoutRegion> {
+    fn from(layout: RegionLayout) -> Self {
+        layout.regions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn layout() -> RegionLayout {
+        RegionLayout::builder()
+            .region("audio", 64)
+            .region("text", 64)
+            .region("motor", 32)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_ranges_are_adjacent() {
+        let layout = layout();
+        assert_eq!(layout.range("audio"), 0..64);
+        assert_eq!(layout.range("text"), 64..128);
+        assert_eq!(layout.range("motor"), 128..160);
+        assert_eq!(layout.total_dims(), 160);
+        assert!(layout.validate().is_ok());
+
+        for pair in layout.regions().windows(2) {
+            assert_eq!(pair[0].range.end, pair[1].range.start);
+        }
+    }
+
+    #[test]
+    fn test_monitored_and_config() {
+        let layout = layout();
+        let monitored = layout.monitored(&[("text", 1000), ("motor", 500)]);
+        assert_eq!(monitored.len(), 2);
+        assert_eq!(monitored[0].name, "text");
+        assert_eq!(monitored[0].range, 64..128);
+        assert_eq!(monitored[1].on_threshold, 500);
+
+        let config = layout.config(50, 242);
+        assert_eq!(config.dims, 160);
+        assert_eq!(config.frame_count, 50);
+        assert!(layout.check_config(&config).is_ok());
+    }
+
+    #[test]
+    fn test_dims_mismatch() {
+        let layout = layout();
+        assert_eq!(
+            layout.check_dims(128),
+            Err(LayoutError::DimsMismatch {
+                layout: 160,
+                field: 128
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_layout_rejected() {
+        let gap = vec![
+            LayoutRegion {
+                name: "a".into(),
+                range: 0..8,
+            },
+            LayoutRegion {
+                name: "b".into(),
+                range: 10..16,
+            },
+        ];
+        assert!(matches!(
+            RegionLayout::try_from(gap),
+            Err(LayoutError::NotContiguous {
+                expected_start: 8,
+                ..
+            })
+        ));
+
+        let dup = vec![
+            LayoutRegion {
+                name: "a".into(),
+                range: 0..8,
+            },
+            LayoutRegion {
+                name: "a".into(),
+                range: 8..16,
+            },
+        ];
+        assert_eq!(
+            RegionLayout::try_from(dup),
+            Err(LayoutError::DuplicateName("a".into()))
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_duplicate_name() {
+        let built = RegionLayout::builder()
+            .region("audio", 64)
+            .region("text", 64)
+            .region("audio", 32)
+            .build();
+        assert_eq!(built, Err(LayoutError::DuplicateName("audio".into())));
+    }
+
+    #[test]
+    fn test_unknown_region() {
+        assert_eq!(layout().get("vision"), None);
+    }
+}
@@ -37,6 +37,7 @@
 //! | threshold | u32 | sum of magnitude² | 524288 = 32 dims × 128² |
 //! | weight | u8 | 100 = 1.0× | 150 = 1.5×, 80 = 0.8× |
 //! | energy | u32 | Σ(magnitude²) | max = 64 × 255² = 4,161,600 |
+//! | memory | usize | bytes | 1024 dims × 64 frames ≈ 196,608 + overhead |
 //!
 //! # Example: Multimodal Binding Detection
 //!
@@ -59,19 +60,20 @@
 //!
 //! // 2. Configure monitored regions (what triggers events)
 //! // Threshold: energy needed to activate (e.g., 100_000 = ~50 dims at mag 45)
-//! field.monitor_region(MonitoredRegion::new("audio", AUDIO_REGION, 100_000));
-//! field.monitor_region(MonitoredRegion::new("text", TEXT_REGION, 100_000));
+//! field.monitor_region(MonitoredRegion::new("audio", AUDIO_REGION, 100_000)).unwrap();
+//! field.monitor_region(MonitoredRegion::new("text", TEXT_REGION, 100_000)).unwrap();
 //! field.set_convergence_threshold(2); // Fire when 2+ regions active
 //!
 //! // 3. Subscribe Reader (binding detector)
 //! field.subscribe(Arc::new(FnObserver(|event| {
 //!     match event {
-//!         FieldEvent::Convergence { active_regions, total_energy } => {
+//!         FieldEvent::Convergence { active_regions, total_energy, .. } => {
 //!             // Binding opportunity! Audio + text co-occurred
-//!             println!("BINDING: {} regions, energy={}", active_regions.len(), total_energy);
+//!             let names: Vec<&str> = active_regions.iter().map(|r| r.name.as_str()).collect();
+//!             println!("BINDING: {:?}, energy={}", names, total_energy);
 //!         }
-//!         FieldEvent::RegionActive { region, energy, .. } => {
-//!             println!("Region {:?} activated with energy {}", region, energy);
+//!         FieldEvent::RegionActive { name, region, energy, .. } => {
+//!             println!("Region {} {:?} activated with energy {}", name, region, energy);
 //!         }
 //!         _ => {}
 //!     }
@@ -79,11 +81,11 @@
 //!
 //! // 4. Writers write to their regions using Signals
 //! // Cochlea writes audio features (magnitude 128 = moderate activation)
-//! let audio_features = vec![Signal::positive(128); 64];
+//! let audio_features = vec![Signal::positive_amplified(128, 1); 64];
 //! field.write_region(&audio_features, AUDIO_REGION);
 //!
 //! // Tokenizer writes text embedding
-//! let text_embedding = vec![Signal::positive(100); 64];
+//! let text_embedding = vec![Signal::positive_amplified(100, 1); 64];
 //! field.write_region(&text_embedding, TEXT_REGION);
 //! // ^ This triggers Convergence event because both regions are now active
 //!
@@ -95,16 +97,94 @@
 //!
 //! The field doesn't know what audio or text means. It just knows that patterns
 //! co-occurred within a temporal window. Meaning emerges from the binding.
+//!
+//! # `no_std`
+//!
+//! The `std` feature is on by default. Without it the crate builds on
+//! `core` + `alloc` for embedded targets: writes, ticks, observers and the
+//! event log all work, with these differences:
+//!
+//! - Observer panics are not caught; the target's panic strategy applies.
+//! - `ChannelObserver`, `RecordingObserver::wait_for` and `spawn_ticker`
+//!   are unavailable.
+//! - [`TemporalField`] is `Send` but not `Sync`.
+//! - `MetricsObserver` needs 64-bit atomics.
+//! - `serde` still pulls in `std` through `ternary-signal`.
+//!
+//! The `tokio`, `crossbeam` and `rayon` features enable `std`.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+
+mod bank;
+mod clock;
+mod commands;
 mod config;
+#[cfg(feature = "crossbeam")]
+mod crossbeam_observer;
+mod event_log;
 mod field;
+mod group;
+mod layout;
+#[cfg(target_has_atomic = "64")]
+mod metrics;
 mod observer;
+mod region;
+mod schedule;
+mod shared;
+#[cfg(feature = "simd")]
+mod simd;
+mod sparse;
+#[cfg(feature = "futures")]
+mod stream;
+mod sync;
+#[cfg(feature = "std")]
+mod ticker;
+#[cfg(feature = "tokio")]
+mod tokio_observer;
 mod vector;
 
-pub use config::FieldConfig;
-pub use field::TemporalField;
-pub use observer::{FieldEvent, FieldObserver, FnObserver, MonitoredRegion, TriggerConfig};
-pub use vector::FieldVector;
+pub use bank::{BankEvent, BankObserver, FieldBank};
+pub use clock::{Clock, ManualClock};
+pub use commands::FieldCommands;
+pub use config::{AdvanceMode, DecayMode, FieldConfig, FrameStorage};
+pub use event_log::EventLog;
+pub use field::{FieldError, MergeMode, RegionWriter, TemporalField};
+pub use group::FieldGroup;
+pub use layout::{LayoutError, LayoutRegion, RegionLayout, RegionLayoutBuilder};
+#[cfg(target_has_atomic = "64")]
+pub use metrics::{MetricsObserver, MetricsSnapshot};
+pub use observer::{
+    ActiveRegion, AdaptiveThreshold, CompositeRegion, ConvergenceGroup, CorrelationMonitor,
+    DropPolicy, EnergyMetric, EventMask, EventTrace, FieldEvent, FieldObserver, FnMutObserver,
+    FnObserver, Habituation, Inhibition, InhibitionMode, MonitoredRegion, NoveltyThreshold,
+    PoolMode, Prototype, RecordingObserver, RegionStats, SequenceTemplate, SubscriptionGuard,
+    SubscriptionId, TemplateMatch, Trend, TriggerConfig,
+};
+pub use region::RegionSpec;
+pub use schedule::{ScheduledId, ScheduledWrite};
+pub use shared::{SharedField, SharedWriter};
+pub use sparse::SparseFieldVector;
+#[cfg(feature = "futures")]
+pub use stream::EventStream;
+pub use vector::{FieldVector, FrameRef};
+
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+#[cfg(feature = "std")]
+pub use observer::ChannelObserver;
+#[cfg(feature = "std")]
+pub use ticker::TickerHandle;
+
+#[cfg(feature = "crossbeam")]
+pub use crossbeam_observer::CrossbeamObserver;
+#[cfg(feature = "tokio")]
+pub use tokio_observer::{
+    AsyncFieldObserver, AsyncObserverAdapter, RegionStates, TokioChannelObserver, WatchObserver,
+};
 
 // Signal: Re-export from ternary-signal (the authoritative source)
 pub use ternary_signal::Signal;
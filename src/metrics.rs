@@ -0,0 +1,288 @@
+//! Metrics observer - event counters for dashboards
+//!
+//! Counts events per type and per region, and remembers the last tick each
+//! type was seen. Atomics only: it can stay attached in production without
+//! putting a lock on the writer's path.
+
+use crate::observer::{EventMask, FieldEvent, FieldObserver, MonitoredRegion};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Number of event types tracked.
+const KIND_COUNT: usize = 19;
+
+/// Event types tracked, in EventMask bit order.
+const KINDS: [(&str, EventMask); KIND_COUNT] = [
+    ("RegionActive", EventMask::REGION_ACTIVE),
+    ("RegionQuiet", EventMask::REGION_QUIET),
+    ("Convergence", EventMask::CONVERGENCE),
+    ("Peak", EventMask::PEAK),
+    ("ConvergenceEnded", EventMask::CONVERGENCE_ENDED),
+    ("Tick", EventMask::TICK),
+    ("SaturationWarning", EventMask::SATURATION),
+    ("GlobalActive", EventMask::GLOBAL_ACTIVE),
+    ("GlobalQuiet", EventMask::GLOBAL_QUIET),
+    ("FieldQuiet", EventMask::FIELD_QUIET),
+    ("Onset", EventMask::ONSET),
+    ("SequenceMatched", EventMask::SEQUENCE),
+    ("Correlated", EventMask::CORRELATED),
+    ("RegionDense", EventMask::DENSE),
+    ("Recognized", EventMask::RECOGNIZED),
+    ("Novelty", EventMask::NOVELTY),
+    ("FrameAdvanced", EventMask::FRAME_ADVANCED),
+    ("ExpectationMet", EventMask::EXPECTATION_MET),
+    ("ExpectationTimeout", EventMask::EXPECTATION_TIMEOUT),
+];
+
+type Counters = [AtomicU64; KIND_COUNT];
+
+fn counters() -> Counters {
+    core::array::from_fn(|_| AtomicU64::new(0))
+}
+
+/// Sum the counters for every kind selected by `mask`.
+fn sum(counters: &Counters, mask: &EventMask) -> u64 {
+    KINDS
+        .iter()
+        .zip(counters)
+        .filter(|((_, kind), _)| mask.contains(kind))
+        .map(|(_, count)| count.load(Ordering::Relaxed))
+        .sum()
+}
+
+/// Observer that counts events per type and per region.
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use temporal_field::{EventMask, FieldConfig, MetricsObserver, MonitoredRegion, Signal, TemporalField};
+///
+/// let mut field = TemporalField::new(FieldConfig::new(64, 10, 242));
+/// field.monitor_region(MonitoredRegion::new("audio", 0..64, 1000)).unwrap();
+///
+/// let metrics = Arc::new(MetricsObserver::with_regions(&field.triggers().regions));
+/// field.subscribe(metrics.clone());
+///
+/// field.write_region(&[Signal::positive_amplified(100, 1); 64], 0..64);
+/// assert_eq!(metrics.count_for_region("audio", &EventMask::REGION_ACTIVE), 1);
+/// ```
+#[derive(Debug)]
+pub struct MetricsObserver {
+    counts: Counters,
+    /// Last tick seen per kind, plus one (0 = never)
+    last_ticks: Counters,
+    /// Regions with their own counters
+    regions: Vec<(String, Range<usize>)>,
+    region_counts: Vec<Counters>,
+}
+
+/// Point-in-time copy of a [`MetricsObserver`]'s counters.
+///
+/// Keyed by event type name (e.g. "RegionActive"); types never seen are
+/// omitted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MetricsSnapshot {
+    /// Events seen per type
+    pub counts: BTreeMap<String, u64>,
+    /// Last tick each type was seen at
+    pub last_ticks: BTreeMap<String, u64>,
+    /// Events per type for each tracked region
+    pub regions: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+impl MetricsObserver {
+    /// Create an observer with field-wide counters only.
+    pub fn new() -> Self {
+        Self::with_regions(&[])
+    }
+
+    /// Create an observer that also counts events per region.
+    pub fn with_regions(regions: &[MonitoredRegion]) -> Self {
+        Self {
+            counts: counters(),
+            last_ticks: counters(),
+            regions: regions
+                .iter()
+                .map(|r| (r.name.clone(), r.range.clone()))
+                .collect(),
+            region_counts: regions.iter().map(|_| counters()).collect(),
+        }
+    }
+
+    /// Count events whose type is selected by `mask`.
+    pub fn count(&self, mask: &EventMask) -> u64 {
+        sum(&self.counts, mask)
+    }
+
+    /// Count events of the selected types involving the named region.
+    ///
+    /// Returns 0 for regions this observer does not track.
+    pub fn count_for_region(&self, name: &str, mask: &EventMask) -> u64 {
+        self.regions
+            .iter()
+            .zip(&self.region_counts)
+            .filter(|((n, _), _)| n == name)
+            .map(|(_, counts)| sum(counts, mask))
+            .sum()
+    }
+
+    /// Count events of the selected types for tracked regions overlapping `range`.
+    pub fn count_for_range(&self, range: Range<usize>, mask: &EventMask) -> u64 {
+        self.regions
+            .iter()
+            .zip(&self.region_counts)
+            .filter(|((_, r), _)| r.start < range.end && range.start < r.end)
+            .map(|(_, counts)| sum(counts, mask))
+            .sum()
+    }
+
+    /// Get the latest tick at which any selected type was seen.
+    pub fn last_tick(&self, mask: &EventMask) -> Option<u64> {
+        KINDS
+            .iter()
+            .zip(&self.last_ticks)
+            .filter(|((_, kind), _)| mask.contains(kind))
+            .map(|(_, tick)| tick.load(Ordering::Relaxed))
+            .max()
+            .and_then(|tick| tick.checked_sub(1))
+    }
+
+    /// Copy the current counters.
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let mut snapshot = MetricsSnapshot::default();
+        for (k, (name, _)) in KINDS.iter().enumerate() {
+            let count = self.counts[k].load(Ordering::Relaxed);
+            if count > 0 {
+                snapshot.counts.insert(name.to_string(), count);
+            }
+            if let Some(tick) = self.last_ticks[k].load(Ordering::Relaxed).checked_sub(1) {
+                snapshot.last_ticks.insert(name.to_string(), tick);
+            }
+        }
+        for ((region, _), counts) in self.regions.iter().zip(&self.region_counts) {
+            let per_kind: BTreeMap<_, _> = KINDS
+                .iter()
+                .zip(counts)
+                .map(|((name, _), count)| (name.to_string(), count.load(Ordering::Relaxed)))
+                .filter(|&(_, count)| count > 0)
+                .collect();
+            if !per_kind.is_empty() {
+                snapshot.regions.insert(region.clone(), per_kind);
+            }
+        }
+        snapshot
+    }
+
+    /// Bump the counter of `kind` for the named region, if tracked.
+    fn count_region(&self, name: &str, kind: usize) {
+        for ((n, _), counts) in self.regions.iter().zip(&self.region_counts) {
+            if n == name {
+                counts[kind].fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+impl Default for MetricsObserver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FieldObserver for MetricsObserver {
+    fn on_event(&self, event: &FieldEvent) {
+        let mask = event.mask();
+        let Some(kind) = KINDS.iter().position(|(_, k)| *k == mask) else {
+            return;
+        };
+        self.counts[kind].fetch_add(1, Ordering::Relaxed);
+        self.last_ticks[kind].fetch_max(event.tick() + 1, Ordering::Relaxed);
+
+        match event {
+            FieldEvent::RegionActive { name, .. }
+            | FieldEvent::RegionQuiet { name, .. }
+            | FieldEvent::Peak { name, .. }
+            | FieldEvent::Onset { name, .. }
+            | FieldEvent::SaturationWarning { name, .. }
+            | FieldEvent::RegionDense { name, .. }
+            | FieldEvent::Recognized { name, .. }
+            | FieldEvent::Novelty { name, .. }
+            | FieldEvent::ExpectationMet { name, .. }
+            | FieldEvent::ExpectationTimeout { name, .. } => self.count_region(name, kind),
+            FieldEvent::Convergence { active_regions, .. }
+            | FieldEvent::ConvergenceEnded { active_regions, .. } => {
+                for region in active_regions {
+                    self.count_region(&region.name, kind);
+                }
+            }
+            FieldEvent::Correlated { a, b, .. } => {
+                self.count_region(a, kind);
+                self.count_region(b, kind);
+            }
+            FieldEvent::GlobalActive { .. }
+            | FieldEvent::GlobalQuiet { .. }
+            | FieldEvent::FieldQuiet { .. }
+            | FieldEvent::FrameAdvanced { .. }
+            | FieldEvent::SequenceMatched { .. }
+            | FieldEvent::Tick { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FieldConfig, TemporalField};
+    use std::sync::Arc;
+    use ternary_signal::Signal;
+
+    #[test]
+    fn test_counts_scripted_sequence() {
+        let config = FieldConfig::new(16, 10, 128);
+        let mut field = TemporalField::new(config);
+        field
+            .monitor_region(MonitoredRegion::new("audio", 0..8, 10_000))
+            .unwrap();
+        field
+            .monitor_region(MonitoredRegion::new("text", 8..16, 10_000))
+            .unwrap();
+
+        let metrics = Arc::new(MetricsObserver::with_regions(&field.triggers().regions));
+        field.subscribe(metrics.clone());
+
+        // Tick 0: both regions activate and converge (8 × 100² = 80,000)
+        field.write_region(&[Signal::positive_amplified(100, 1); 8], 0..8);
+        field.write_region(&[Signal::positive_amplified(100, 1); 8], 8..16);
+        // Halving each tick: 20,000 at tick 1 (peak), 5,000 at tick 2 (quiet)
+        field.tick_n(3);
+
+        assert_eq!(metrics.count(&EventMask::REGION_ACTIVE), 2);
+        assert_eq!(metrics.count(&EventMask::REGION_QUIET), 2);
+        assert_eq!(metrics.count(&EventMask::CONVERGENCE), 1);
+        assert_eq!(metrics.count(&EventMask::CONVERGENCE_ENDED), 1);
+        assert_eq!(
+            metrics.count(&(EventMask::REGION_ACTIVE | EventMask::REGION_QUIET)),
+            4
+        );
+        assert_eq!(metrics.count(&EventMask::PEAK), 2);
+        assert_eq!(metrics.count_for_region("audio", &EventMask::ALL), 4);
+        assert_eq!(metrics.count_for_range(4..12, &EventMask::REGION_ACTIVE), 2);
+        assert_eq!(metrics.count_for_region("vision", &EventMask::ALL), 0);
+        assert_eq!(metrics.last_tick(&EventMask::REGION_ACTIVE), Some(0));
+        assert_eq!(metrics.last_tick(&EventMask::REGION_QUIET), Some(2));
+        assert_eq!(metrics.last_tick(&EventMask::PEAK), Some(1));
+        assert_eq!(metrics.last_tick(&EventMask::ONSET), None);
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.counts["Convergence"], 1);
+        assert_eq!(snapshot.last_ticks["RegionQuiet"], 2);
+        assert_eq!(snapshot.regions["text"]["RegionActive"], 1);
+        assert!(!snapshot.counts.contains_key("Onset"));
+    }
+}
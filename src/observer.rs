@@ -16,87 +16,1228 @@
 //! When energy is between the thresholds, the previous state is maintained.
 //! This provides stable edge-triggered semantics without explicit refractory periods.
 
-use std::ops::Range;
+use crate::region::RegionSpec;
+use crate::sync::Mutex;
+use crate::vector::FrameRef;
+use alloc::string::String;
+use alloc::sync::{Arc, Weak};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{BitOr, BitOrAssign, Range};
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use ternary_signal::Signal;
+
+#[cfg(feature = "std")]
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(feature = "std")]
+use std::sync::Condvar;
+#[cfg(feature = "std")]
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Event emitted when field activity crosses a threshold
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FieldEvent {
     /// A region became active (energy crossed on_threshold from below)
     RegionActive {
+        /// Name of the monitored region
+        name: String,
         region: Range<usize>,
-        /// Energy as sum of squared magnitudes
+        /// Dims covered by the region (`region` is their bounding range)
+        spec: RegionSpec,
+        /// Energy under the region's metric
         energy: u64,
+        /// Metric that produced `energy`
+        metric: EnergyMetric,
         /// The on_threshold that was crossed
         threshold: u64,
+        /// Adaptive baseline energy at fire time (0 for static thresholds)
+        baseline: u64,
+        /// Magnitude-weighted mean dim of the current frame (None unless the
+        /// region sets `report_centroid`)
+        centroid: Option<usize>,
+        /// Tick at which the event fired
+        tick: u64,
     },
     /// A region went quiet (energy dropped below off_threshold)
     RegionQuiet {
+        /// Name of the monitored region
+        name: String,
         region: Range<usize>,
-        /// Energy as sum of squared magnitudes
+        /// Dims covered by the region (`region` is their bounding range)
+        spec: RegionSpec,
+        /// Energy under the region's metric
         energy: u64,
+        /// Metric that produced `energy`
+        metric: EnergyMetric,
         /// The off_threshold that was crossed
         threshold: u64,
+        /// Adaptive baseline energy at fire time (0 for static thresholds)
+        baseline: u64,
+        /// Tick at which the event fired
+        tick: u64,
     },
-    /// Multiple regions active simultaneously (binding opportunity)
+    /// Multiple regions became active simultaneously (binding opportunity)
+    ///
+    /// Edge-triggered: fires once when the active count reaches the
+    /// convergence threshold, not on every write while it stays there.
     Convergence {
-        active_regions: Vec<Range<usize>>,
+        /// Convergence group that converged (None = all monitored regions)
+        group: Option<String>,
+        active_regions: Vec<ActiveRegion>,
         /// Total weighted energy (sum of energy × weight for each active region)
         total_energy: u64,
+        /// Tick at which the event fired
+        tick: u64,
+    },
+    /// Active region count dropped back below the convergence threshold
+    ConvergenceEnded {
+        /// Convergence group that ended (None = all monitored regions)
+        group: Option<String>,
+        /// Regions of the convergence that ended, as its Convergence event
+        /// reported them
+        converged_regions: Vec<ActiveRegion>,
+        /// Regions still active after convergence ended
+        active_regions: Vec<ActiveRegion>,
+        /// Total weighted energy of the remaining active regions
+        total_energy: u64,
+        /// Tick at which the event fired
+        tick: u64,
     },
     /// Peak detected in a region (local maximum)
     Peak {
+        /// Name of the monitored region
+        name: String,
+        region: Range<usize>,
+        /// Dims covered by the region (`region` is their bounding range)
+        spec: RegionSpec,
+        /// Energy under the region's metric
+        energy: u64,
+        /// Metric that produced `energy`
+        metric: EnergyMetric,
+        /// Tick at which the peak occurred (not when it was detected)
+        tick: u64,
+    },
+    /// A region's energy rose faster than its onset rate between ticks
+    ///
+    /// Independent of on_threshold: a quiet but sharp onset still fires.
+    Onset {
+        /// Name of the monitored region
+        name: String,
+        region: Range<usize>,
+        /// Dims covered by the region (`region` is their bounding range)
+        spec: RegionSpec,
+        /// Energy gained since the previous tick
+        delta_energy: u64,
+        /// Tick at which the rise was sampled
+        tick: u64,
+    },
+    /// A region's occupancy crossed its density threshold from below
+    ///
+    /// Occupancy is the per mille of the region's dims that are nonzero.
+    /// Edge-triggered with hysteresis like RegionActive: re-arms once
+    /// occupancy drops below the region's off density.
+    RegionDense {
+        /// Name of the monitored region
+        name: String,
+        region: Range<usize>,
+        /// Dims covered by the region (`region` is their bounding range)
+        spec: RegionSpec,
+        /// Nonzero dims per mille of the region's dims
+        occupancy_permille: u16,
+        /// Tick at which the event fired
+        tick: u64,
+    },
+    /// A region's content moved away from its recent average
+    ///
+    /// Edge-triggered with hysteresis: sustained novel input fires once,
+    /// and re-arms when the distance drops below the region's off distance.
+    Novelty {
+        /// Name of the monitored region
+        name: String,
+        region: Range<usize>,
+        /// Dims covered by the region (`region` is their bounding range)
+        spec: RegionSpec,
+        /// Σ|current − mean| over the region's dims, in effective values
+        distance: u64,
+        /// Tick at which the event fired
+        tick: u64,
+    },
+    /// A region went active holding a pattern close to a stored prototype
+    ///
+    /// Fires right after the region's RegionActive, for the most similar
+    /// prototype only.
+    Recognized {
+        /// Name of the monitored region
+        name: String,
         region: Range<usize>,
-        /// Energy as sum of squared effective magnitudes
+        /// Dims covered by the region (`region` is their bounding range)
+        spec: RegionSpec,
+        /// Name of the matched prototype
+        prototype: String,
+        /// Similarity per mille (see `FieldVector::similarity_permille`)
+        similarity_permille: u16,
+        /// Tick at which the event fired
+        tick: u64,
+    },
+    /// A region's dims started clipping at the ±65,025 ceiling
+    ///
+    /// Edge-triggered: fires once when the saturated fraction exceeds the
+    /// region's `saturation_permille`, re-arms when it drops back below.
+    SaturationWarning {
+        /// Name of the monitored region
+        name: String,
+        region: Range<usize>,
+        /// Dims covered by the region (`region` is their bounding range)
+        spec: RegionSpec,
+        /// Dims at the saturation ceiling
+        saturated_dims: usize,
+        /// Dims in the region
+        total_dims: usize,
+        /// Tick at which the event fired
+        tick: u64,
+    },
+    /// Whole-frame energy crossed the global on_threshold from below
+    GlobalActive {
+        /// Energy of the current frame over all dims
         energy: u64,
+        /// The global on_threshold that was crossed
+        threshold: u64,
+        /// Tick at which the event fired
+        tick: u64,
+    },
+    /// Whole-frame energy dropped below the global off_threshold
+    GlobalQuiet {
+        /// Energy of the current frame over all dims
+        energy: u64,
+        /// The global off_threshold that was crossed
+        threshold: u64,
+        /// Tick at which the event fired
+        tick: u64,
+    },
+    /// Decay drove every frame of the field to zero
+    ///
+    /// One-shot: re-armed when a write makes the field nonzero again.
+    FieldQuiet {
+        /// Tick at which the field fell silent
+        tick: u64,
+    },
+    /// The write head moved onto a new frame
+    FrameAdvanced {
+        /// Index of the new current frame
+        write_head: usize,
+        /// Tick at which the head advanced
+        tick: u64,
+    },
+    /// A registered sequence of region activations completed in order
+    SequenceMatched {
+        /// Name of the sequence template
+        name: String,
+        /// Tick at which each step's region became active
+        ticks: Vec<u64>,
+    },
+    /// Two active regions hold similar patterns
+    ///
+    /// Edge-triggered: fires once when similarity exceeds the threshold while
+    /// both regions are active, re-arms when either condition lapses.
+    Correlated {
+        /// Name of the first region
+        a: String,
+        /// Name of the second region
+        b: String,
+        /// Similarity per mille (see `FieldVector::similarity_permille`)
+        similarity: u16,
+        /// Tick at which the event fired
+        tick: u64,
+    },
+    /// An expected region activation arrived in time (opt-in via
+    /// `emit_expectation_met`)
+    ExpectationMet {
+        /// Name of the monitored region
+        name: String,
+        region: Range<usize>,
+        /// The fulfilled expectation
+        expectation: ExpectationId,
+        /// Tick at which the region became active
+        tick: u64,
+    },
+    /// An expected region activation did not arrive by its deadline
+    ExpectationTimeout {
+        /// Name of the monitored region
+        name: String,
+        region: Range<usize>,
+        /// The expectation that lapsed
+        expectation: ExpectationId,
+        /// Tick at which the timeout fired (the deadline, unless a
+        /// `tick_by` jump passed it)
+        tick: u64,
+    },
+    /// Heartbeat fired at the end of every tick (opt-in via `emit_tick_events`)
+    Tick {
         tick: u64,
+        /// Current-frame energy summed over monitored regions
+        /// (whole frame when no regions are configured)
+        total_energy: u64,
     },
 }
 
+/// Energy statistics of a monitored region over its recent ticks.
+///
+/// Integer only: variance is `Σx²/n − (Σx/n)²`, computed exactly in u128
+/// and saturated to u64.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RegionStats {
+    /// Ticks covered
+    pub samples: u32,
+    /// Mean energy (rounded down)
+    pub mean: u64,
+    /// Energy variance (rounded down)
+    pub variance: u64,
+    /// Lowest energy seen
+    pub min: u64,
+    /// Highest energy seen
+    pub max: u64,
+    /// Per mille of ticks the region was in the active state
+    pub duty_cycle_permille: u16,
+}
+
+impl RegionStats {
+    /// Compute statistics from per-tick `(energy, active)` samples.
+    pub fn from_samples(samples: impl IntoIterator<Item = (u64, bool)>) -> Self {
+        let mut stats = RegionStats {
+            min: u64::MAX,
+            ..Default::default()
+        };
+        let (mut sum, mut sum_sq, mut active) = (0u128, 0u128, 0u64);
+        for (energy, is_active) in samples {
+            stats.samples += 1;
+            stats.min = stats.min.min(energy);
+            stats.max = stats.max.max(energy);
+            sum += energy as u128;
+            sum_sq += energy as u128 * energy as u128;
+            active += is_active as u64;
+        }
+        if stats.samples == 0 {
+            return RegionStats::default();
+        }
+        let n = stats.samples as u128;
+        stats.mean = (sum / n) as u64;
+        let variance = (n * sum_sq).saturating_sub(sum * sum) / (n * n);
+        stats.variance = variance.min(u64::MAX as u128) as u64;
+        stats.duty_cycle_permille = (active * 1000 / n as u64) as u16;
+        stats
+    }
+}
+
+/// Direction of a region's energy over a window of frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Trend {
+    /// Newer frames hold more energy than older ones
+    Rising,
+    /// Newer frames hold less energy than older ones
+    Falling,
+    /// No change (or too few frames to tell)
+    Flat,
+}
+
+impl Trend {
+    /// Classify a signed energy change.
+    pub fn from_delta(delta: i64) -> Self {
+        match delta {
+            d if d > 0 => Trend::Rising,
+            d if d < 0 => Trend::Falling,
+            _ => Trend::Flat,
+        }
+    }
+}
+
+/// How several signals collapse into one: across frames in
+/// [`read_pooled`](crate::TemporalField::read_pooled), across neighbouring
+/// dims in [`FieldVector::downsample`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PoolMode {
+    /// The signal with the largest effective magnitude (first on ties)
+    #[default]
+    Max,
+    /// The mean effective value (as [`region_mean`](crate::TemporalField::region_mean))
+    Mean,
+    /// The sum of polarity × magnitude, clamped to ±255 (the i16 path)
+    Sum,
+}
+
+impl PoolMode {
+    /// Collapse signals into one. Returns zero for no signals.
+    pub fn pool(self, signals: impl IntoIterator<Item = Signal>) -> Signal {
+        let signals = signals.into_iter();
+        match self {
+            PoolMode::Max => signals.fold(Signal::ZERO, |best, s| {
+                if s.effective_magnitude() > best.effective_magnitude() {
+                    s
+                } else {
+                    best
+                }
+            }),
+            PoolMode::Mean => {
+                let (sum, n) = signals.fold((0i64, 0i64), |(sum, n), s| {
+                    (sum + s.current() as i64, n + 1)
+                });
+                if n == 0 {
+                    return Signal::ZERO;
+                }
+                Signal::from_current((sum / n) as i32)
+            }
+            PoolMode::Sum => {
+                let sum: i32 = signals
+                    .map(|s| s.polarity as i32 * s.magnitude as i32)
+                    .sum();
+                Signal::from_current(sum.clamp(-255, 255))
+            }
+        }
+    }
+
+    /// Pool `width` columns across rows of signals: the same result as
+    /// [`pool`](Self::pool) over each column in row order, but walking
+    /// every row front to back. Zeros for no rows.
+    pub(crate) fn pool_rows<'a>(
+        self,
+        rows: impl IntoIterator<Item = &'a [Signal]>,
+        width: usize,
+    ) -> Vec<Signal> {
+        let rows = rows.into_iter();
+        match self {
+            PoolMode::Max => {
+                let mut best = vec![Signal::ZERO; width];
+                for row in rows {
+                    for (best, &s) in best.iter_mut().zip(row) {
+                        if s.effective_magnitude() > best.effective_magnitude() {
+                            *best = s;
+                        }
+                    }
+                }
+                best
+            }
+            PoolMode::Mean => {
+                let mut sums = vec![0i64; width];
+                let mut n = 0i64;
+                for row in rows {
+                    n += 1;
+                    for (sum, s) in sums.iter_mut().zip(row) {
+                        *sum += s.current() as i64;
+                    }
+                }
+                if n == 0 {
+                    return vec![Signal::ZERO; width];
+                }
+                sums.into_iter()
+                    .map(|sum| Signal::from_current((sum / n) as i32))
+                    .collect()
+            }
+            PoolMode::Sum => {
+                let mut sums = vec![0i32; width];
+                for row in rows {
+                    for (sum, s) in sums.iter_mut().zip(row) {
+                        *sum += s.polarity as i32 * s.magnitude as i32;
+                    }
+                }
+                sums.into_iter()
+                    .map(|sum| Signal::from_current(sum.clamp(-255, 255)))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Best match of a template within the recent frames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TemplateMatch {
+    /// Frames back from the write head (0 = current frame)
+    pub frames_back: usize,
+    /// Dot product of template and frame over the range
+    pub score: i64,
+    /// Score per mille of the template's own dot product (1000 = the frame
+    /// projects onto the template as strongly as the template itself; 0 for
+    /// a silent template)
+    pub score_permille: i64,
+}
+
+/// A region taking part in a Convergence event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ActiveRegion {
+    /// Name of the monitored region
+    pub name: String,
+    /// Dimension range
+    pub range: Range<usize>,
+    /// Dims covered by the region (`range` is their bounding range)
+    pub spec: RegionSpec,
+    /// Energy under the region's metric (unweighted)
+    pub energy: u64,
+    /// Metric that produced `energy`
+    pub metric: EnergyMetric,
+    /// Tick at which the region last fired RegionActive
+    pub activated_at: u64,
+}
+
+impl FieldEvent {
+    /// Get the tick this event refers to.
+    ///
+    /// For Peak this is when the peak occurred; for all other events it is
+    /// the tick at which the event fired (0 before the first tick).
+    pub fn tick(&self) -> u64 {
+        match self {
+            FieldEvent::RegionActive { tick, .. }
+            | FieldEvent::RegionQuiet { tick, .. }
+            | FieldEvent::Convergence { tick, .. }
+            | FieldEvent::ConvergenceEnded { tick, .. }
+            | FieldEvent::Peak { tick, .. }
+            | FieldEvent::Onset { tick, .. }
+            | FieldEvent::SaturationWarning { tick, .. }
+            | FieldEvent::RegionDense { tick, .. }
+            | FieldEvent::Recognized { tick, .. }
+            | FieldEvent::Novelty { tick, .. }
+            | FieldEvent::GlobalActive { tick, .. }
+            | FieldEvent::GlobalQuiet { tick, .. }
+            | FieldEvent::FieldQuiet { tick }
+            | FieldEvent::FrameAdvanced { tick, .. }
+            | FieldEvent::Correlated { tick, .. }
+            | FieldEvent::ExpectationMet { tick, .. }
+            | FieldEvent::ExpectationTimeout { tick, .. }
+            | FieldEvent::Tick { tick, .. } => *tick,
+            // Completes on the last step's activation
+            FieldEvent::SequenceMatched { ticks, .. } => ticks.last().copied().unwrap_or(0),
+        }
+    }
+
+    /// Get the mask bit for this event's type.
+    pub fn mask(&self) -> EventMask {
+        match self {
+            FieldEvent::RegionActive { .. } => EventMask::REGION_ACTIVE,
+            FieldEvent::RegionQuiet { .. } => EventMask::REGION_QUIET,
+            FieldEvent::Convergence { .. } => EventMask::CONVERGENCE,
+            FieldEvent::ConvergenceEnded { .. } => EventMask::CONVERGENCE_ENDED,
+            FieldEvent::Peak { .. } => EventMask::PEAK,
+            FieldEvent::Onset { .. } => EventMask::ONSET,
+            FieldEvent::SaturationWarning { .. } => EventMask::SATURATION,
+            FieldEvent::GlobalActive { .. } => EventMask::GLOBAL_ACTIVE,
+            FieldEvent::GlobalQuiet { .. } => EventMask::GLOBAL_QUIET,
+            FieldEvent::FieldQuiet { .. } => EventMask::FIELD_QUIET,
+            FieldEvent::FrameAdvanced { .. } => EventMask::FRAME_ADVANCED,
+            FieldEvent::SequenceMatched { .. } => EventMask::SEQUENCE,
+            FieldEvent::Correlated { .. } => EventMask::CORRELATED,
+            FieldEvent::RegionDense { .. } => EventMask::DENSE,
+            FieldEvent::Recognized { .. } => EventMask::RECOGNIZED,
+            FieldEvent::Novelty { .. } => EventMask::NOVELTY,
+            FieldEvent::ExpectationMet { .. } => EventMask::EXPECTATION_MET,
+            FieldEvent::ExpectationTimeout { .. } => EventMask::EXPECTATION_TIMEOUT,
+            FieldEvent::Tick { .. } => EventMask::TICK,
+        }
+    }
+}
+
+/// Selects which events an observer receives.
+///
+/// Bitflag-style: combine event types with `|`. Optionally restrict
+/// delivery to events involving one named region with `for_region()`, or
+/// to events whose region overlaps a dim range with `for_range()`.
+///
+/// ```rust
+/// use temporal_field::EventMask;
+///
+/// let mask = (EventMask::REGION_ACTIVE | EventMask::REGION_QUIET).for_region("audio");
+/// assert!(mask.contains(&EventMask::REGION_ACTIVE));
+/// assert!(!mask.contains(&EventMask::CONVERGENCE));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventMask {
+    bits: u32,
+    /// Only deliver events involving this region (None = any region)
+    pub region: Option<String>,
+    /// Only deliver events whose region overlaps these dims (None = any dims)
+    pub range: Option<Range<usize>>,
+}
+
+impl EventMask {
+    /// No events.
+    pub const NONE: Self = Self::from_bits(0);
+    /// RegionActive events.
+    pub const REGION_ACTIVE: Self = Self::from_bits(1 << 0);
+    /// RegionQuiet events.
+    pub const REGION_QUIET: Self = Self::from_bits(1 << 1);
+    /// Convergence events.
+    pub const CONVERGENCE: Self = Self::from_bits(1 << 2);
+    /// Peak events.
+    pub const PEAK: Self = Self::from_bits(1 << 3);
+    /// ConvergenceEnded events.
+    pub const CONVERGENCE_ENDED: Self = Self::from_bits(1 << 4);
+    /// Tick heartbeat events.
+    pub const TICK: Self = Self::from_bits(1 << 5);
+    /// SaturationWarning events.
+    pub const SATURATION: Self = Self::from_bits(1 << 6);
+    /// GlobalActive events.
+    pub const GLOBAL_ACTIVE: Self = Self::from_bits(1 << 7);
+    /// GlobalQuiet events.
+    pub const GLOBAL_QUIET: Self = Self::from_bits(1 << 8);
+    /// FieldQuiet events.
+    pub const FIELD_QUIET: Self = Self::from_bits(1 << 9);
+    /// Onset events.
+    pub const ONSET: Self = Self::from_bits(1 << 10);
+    /// SequenceMatched events.
+    pub const SEQUENCE: Self = Self::from_bits(1 << 11);
+    /// Correlated events.
+    pub const CORRELATED: Self = Self::from_bits(1 << 12);
+    /// RegionDense events.
+    pub const DENSE: Self = Self::from_bits(1 << 13);
+    /// Recognized events.
+    pub const RECOGNIZED: Self = Self::from_bits(1 << 14);
+    /// Novelty events.
+    pub const NOVELTY: Self = Self::from_bits(1 << 15);
+    /// FrameAdvanced events.
+    pub const FRAME_ADVANCED: Self = Self::from_bits(1 << 16);
+    /// ExpectationMet events.
+    pub const EXPECTATION_MET: Self = Self::from_bits(1 << 17);
+    /// ExpectationTimeout events.
+    pub const EXPECTATION_TIMEOUT: Self = Self::from_bits(1 << 18);
+    /// Every event type.
+    pub const ALL: Self = Self::from_bits(u32::MAX);
+
+    const fn from_bits(bits: u32) -> Self {
+        Self {
+            bits,
+            region: None,
+            range: None,
+        }
+    }
+
+    /// Get the selected event types as bits.
+    pub(crate) fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Restrict delivery to events involving the named region.
+    pub fn for_region(mut self, name: impl Into<String>) -> Self {
+        self.region = Some(name.into());
+        self
+    }
+
+    /// Restrict delivery to events whose region overlaps `range`.
+    ///
+    /// Convergence passes if any active region overlaps, ConvergenceEnded if
+    /// any of its converged regions does; field-wide events (Global*,
+    /// FieldQuiet, FrameAdvanced, SequenceMatched, Tick) always pass.
+    pub fn for_range(mut self, range: Range<usize>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Check whether every event type in `other` is selected.
+    pub fn contains(&self, other: &EventMask) -> bool {
+        self.bits & other.bits == other.bits
+    }
+
+    /// Check whether no event type is selected.
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+}
+
+impl Default for EventMask {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl BitOr for EventMask {
+    type Output = Self;
+
+    /// Combine event types. The region and range filters of `self` win if set.
+    fn bitor(mut self, rhs: Self) -> Self {
+        self |= rhs;
+        self
+    }
+}
+
+impl BitOrAssign for EventMask {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.bits |= rhs.bits;
+        if self.region.is_none() {
+            self.region = rhs.region;
+        }
+        if self.range.is_none() {
+            self.range = rhs.range;
+        }
+    }
+}
+
 /// Observer that receives field events
+///
+/// Observers are called synchronously from inside writes and ticks, so
+/// `on_event` must never block: hand slow work off (bounded channel,
+/// queue, task) and return. A blocking observer stalls every writer.
+///
+/// Every subscriber borrows the same event, so firing costs no clones.
+/// Observers that keep events (queues, channels, recorders) clone them.
 pub trait FieldObserver: Send + Sync {
     /// Called when a field event occurs
-    fn on_event(&self, event: FieldEvent);
+    fn on_event(&self, event: &FieldEvent);
+}
+
+/// Handle identifying one subscription on a field.
+///
+/// Returned by `TemporalField::subscribe` and passed to `unsubscribe`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(pub(crate) u64);
+
+/// Handle identifying one expected region activation.
+///
+/// Returned by `TemporalField::expect_activation`, passed to
+/// `cancel_expectation`, and carried by the events it resolves to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExpectationId(pub(crate) u64);
+
+/// Subscription that detaches its observer when dropped.
+///
+/// Returned by `TemporalField::subscribe_guarded`. Hold it for as long as the
+/// observer should receive events; dropping it stops delivery.
+#[derive(Debug)]
+pub struct SubscriptionGuard {
+    id: SubscriptionId,
+    alive: Arc<AtomicBool>,
+}
+
+impl SubscriptionGuard {
+    pub(crate) fn new(id: SubscriptionId, alive: Arc<AtomicBool>) -> Self {
+        Self { id, alive }
+    }
+
+    /// Get the underlying subscription id.
+    pub fn id(&self) -> SubscriptionId {
+        self.id
+    }
+}
+
+impl Drop for SubscriptionGuard {
+    fn drop(&mut self) {
+        self.alive.store(false, Ordering::Release);
+    }
+}
+
+/// Strong or weak reference to a subscribed observer.
+pub(crate) enum ObserverRef {
+    /// Field keeps the observer alive
+    Strong(Arc<dyn FieldObserver>),
+    /// Observer lives only as long as its owner's Arc
+    Weak(Weak<dyn FieldObserver>),
+}
+
+/// A registered observer and its bookkeeping.
+pub(crate) struct Subscription {
+    pub(crate) id: SubscriptionId,
+    observer: ObserverRef,
+    /// Which events this observer receives.
+    pub(crate) mask: EventMask,
+    /// Cleared when a SubscriptionGuard is dropped (None = not guarded).
+    pub(crate) alive: Option<Arc<AtomicBool>>,
+    /// Total panics raised by this observer.
+    pub(crate) panics: AtomicU32,
+    /// Panics since the last successful delivery.
+    consecutive_panics: AtomicU32,
+    /// Set once the observer exceeds the field's panic limit.
+    disabled: AtomicBool,
+}
+
+impl Subscription {
+    pub(crate) fn new(
+        id: SubscriptionId,
+        observer: ObserverRef,
+        mask: EventMask,
+        alive: Option<Arc<AtomicBool>>,
+    ) -> Self {
+        Self {
+            id,
+            observer,
+            mask,
+            alive,
+            panics: AtomicU32::new(0),
+            consecutive_panics: AtomicU32::new(0),
+            disabled: AtomicBool::new(false),
+        }
+    }
+
+    /// Check whether the subscription should still receive events.
+    pub(crate) fn is_live(&self) -> bool {
+        !self.disabled.load(Ordering::Acquire) && !self.is_detached()
+    }
+
+    /// Check whether the owner let go (guard dropped or weak observer freed).
+    pub(crate) fn is_detached(&self) -> bool {
+        let guard_dropped = self
+            .alive
+            .as_ref()
+            .is_some_and(|alive| !alive.load(Ordering::Acquire));
+        let freed = match &self.observer {
+            ObserverRef::Strong(_) => false,
+            ObserverRef::Weak(weak) => weak.strong_count() == 0,
+        };
+        guard_dropped || freed
+    }
+
+    /// Deliver an event, containing any panic raised by the observer.
+    ///
+    /// Observers are not required to be `RefUnwindSafe`; a panicking observer
+    /// may leave its own state inconsistent, but never the field's. After
+    /// `panic_limit` consecutive panics the subscription is disabled.
+    ///
+    /// Without `std` panics cannot be caught and are left to the target.
+    pub(crate) fn deliver(&self, event: &FieldEvent, panic_limit: Option<u32>) {
+        let observer = match &self.observer {
+            ObserverRef::Strong(observer) => observer.clone(),
+            ObserverRef::Weak(weak) => match weak.upgrade() {
+                Some(observer) => observer,
+                None => return,
+            },
+        };
+        #[cfg(feature = "std")]
+        let delivered = panic::catch_unwind(AssertUnwindSafe(|| observer.on_event(event))).is_ok();
+        #[cfg(not(feature = "std"))]
+        let delivered = {
+            observer.on_event(event);
+            true
+        };
+        if delivered {
+            self.consecutive_panics.store(0, Ordering::Relaxed);
+            return;
+        }
+        self.panics.fetch_add(1, Ordering::Relaxed);
+        let consecutive = self.consecutive_panics.fetch_add(1, Ordering::Relaxed) + 1;
+        if panic_limit.is_some_and(|limit| consecutive >= limit) {
+            self.disabled.store(true, Ordering::Release);
+        }
+    }
 }
 
 /// Function-based observer for simple cases
-pub struct FnObserver<F: Fn(FieldEvent) + Send + Sync>(pub F);
+pub struct FnObserver<F: Fn(&FieldEvent) + Send + Sync>(pub F);
 
-impl<F: Fn(FieldEvent) + Send + Sync> FieldObserver for FnObserver<F> {
-    fn on_event(&self, event: FieldEvent) {
+impl<F: Fn(&FieldEvent) + Send + Sync> FieldObserver for FnObserver<F> {
+    fn on_event(&self, event: &FieldEvent) {
         (self.0)(event);
     }
 }
 
+/// Function-based observer for closures that mutate captured state.
+///
+/// Wraps an `FnMut` in a `Mutex`, so stateful observers (counters,
+/// histories, debouncers) don't need atomics or their own locking.
+///
+/// ```rust
+/// use temporal_field::{FieldConfig, FieldEvent, FnMutObserver, MonitoredRegion, TemporalField};
+/// use std::sync::Arc;
+///
+/// let mut field = TemporalField::new(FieldConfig::new(8, 10, 128));
+/// field.monitor_region(MonitoredRegion::new("a", 0..8, 10_000)).unwrap();
+///
+/// let mut last_tick = None;
+/// field.subscribe(Arc::new(FnMutObserver::new(move |event: &FieldEvent| {
+///     // Debounce: ignore events within 2 ticks of the previous one
+///     if last_tick.is_some_and(|t| event.tick() < t + 2) {
+///         return;
+///     }
+///     last_tick = Some(event.tick());
+/// })));
+/// ```
+pub struct FnMutObserver<F: FnMut(&FieldEvent) + Send>(Mutex<F>);
+
+impl<F: FnMut(&FieldEvent) + Send> FnMutObserver<F> {
+    /// Wrap a closure.
+    pub fn new(f: F) -> Self {
+        Self(Mutex::new(f))
+    }
+
+    /// Take the closure back out.
+    pub fn into_inner(self) -> F {
+        self.0.into_inner()
+    }
+}
+
+impl<F: FnMut(&FieldEvent) + Send> FieldObserver for FnMutObserver<F> {
+    fn on_event(&self, event: &FieldEvent) {
+        // The lock ignores poisoning, so a panic in an earlier call doesn't
+        // stop delivery
+        (self.0.lock())(event);
+    }
+}
+
 /// Channel-based observer - sends events to a channel
+///
+/// Every event carries its tick, so a consumer can reconstruct the timeline
+/// from the channel alone, long after the events were fired.
+///
+/// ```rust
+/// use temporal_field::{
+///     ChannelObserver, FieldConfig, FieldEvent, MonitoredRegion, Signal, TemporalField,
+/// };
+/// use std::sync::{mpsc, Arc};
+///
+/// let mut field = TemporalField::new(FieldConfig::new(8, 10, 128));
+/// field.monitor_region(MonitoredRegion::new("input", 0..8, 10_000)).unwrap();
+///
+/// let (tx, rx) = mpsc::channel();
+/// field.subscribe(Arc::new(ChannelObserver::new(tx)));
+///
+/// field.tick_n(3);
+/// field.write_region(&[Signal::positive_amplified(100, 1); 8], 0..8); // active at tick 3
+/// field.tick_n(5); // decays to quiet
+///
+/// // Peak reports when the peak occurred, so sort to rebuild the timeline
+/// let mut timeline: Vec<FieldEvent> = rx.try_iter().collect();
+/// timeline.sort_by_key(|event| event.tick());
+///
+/// assert!(matches!(timeline[0], FieldEvent::RegionActive { tick: 3, .. }));
+/// assert!(matches!(timeline.last(), Some(FieldEvent::RegionQuiet { tick, .. }) if *tick > 3));
+/// ```
+#[cfg(feature = "std")]
 pub struct ChannelObserver {
     sender: std::sync::mpsc::Sender<FieldEvent>,
 }
 
+#[cfg(feature = "std")]
 impl ChannelObserver {
+    /// Create an observer that forwards every event to `sender`.
     pub fn new(sender: std::sync::mpsc::Sender<FieldEvent>) -> Self {
         Self { sender }
     }
 }
 
+#[cfg(feature = "std")]
 impl FieldObserver for ChannelObserver {
-    fn on_event(&self, event: FieldEvent) {
-        let _ = self.sender.send(event);
+    fn on_event(&self, event: &FieldEvent) {
+        let _ = self.sender.send(event.clone());
+    }
+}
+
+/// Recorded `(tick, event)` pairs, oldest first.
+///
+/// Cloneable, and serializable with the `serde` feature, so a trace can be
+/// saved for replay or compared in assertions.
+pub type EventTrace = Vec<(u64, FieldEvent)>;
+
+/// Observer that records every event it receives with its tick.
+///
+/// Hand `Arc<RecordingObserver>` straight to `subscribe` and keep a clone
+/// of the Arc to inspect the trace.
+///
+/// ```rust
+/// use temporal_field::{
+///     EventMask, FieldConfig, MonitoredRegion, RecordingObserver, Signal, TemporalField,
+/// };
+/// use std::sync::Arc;
+///
+/// let mut field = TemporalField::new(FieldConfig::new(8, 10, 242));
+/// field.monitor_region(MonitoredRegion::new("a", 0..8, 10_000)).unwrap();
+///
+/// let recorder = Arc::new(RecordingObserver::new());
+/// field.subscribe(recorder.clone());
+///
+/// field.write_region(&[Signal::positive_amplified(100, 1); 8], 0..8);
+/// assert_eq!(recorder.count_of(&EventMask::REGION_ACTIVE), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct RecordingObserver {
+    events: Mutex<EventTrace>,
+    #[cfg(feature = "std")]
+    recorded: Condvar,
+}
+
+impl RecordingObserver {
+    /// Create an empty recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get a copy of the recorded trace.
+    pub fn events(&self) -> EventTrace {
+        self.events.lock().clone()
+    }
+
+    /// Count recorded events whose type is selected by `mask`.
+    pub fn count_of(&self, mask: &EventMask) -> usize {
+        self.events
+            .lock()
+            .iter()
+            .filter(|(_, event)| mask.contains(&event.mask()))
+            .count()
+    }
+
+    /// Get the number of recorded events.
+    pub fn len(&self) -> usize {
+        self.events.lock().len()
+    }
+
+    /// Check if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Discard the recorded trace.
+    pub fn clear(&self) {
+        self.events.lock().clear();
+    }
+
+    /// Block until an event selected by `mask` has been recorded.
+    ///
+    /// Returns the first matching event (already recorded or arriving within
+    /// `timeout`), or None on timeout. For cross-thread tests.
+    #[cfg(feature = "std")]
+    pub fn wait_for(&self, mask: &EventMask, timeout: Duration) -> Option<(u64, FieldEvent)> {
+        let find = |events: &EventTrace| {
+            events
+                .iter()
+                .find(|(_, event)| mask.contains(&event.mask()))
+                .cloned()
+        };
+        let events = self.events.lock();
+        let (events, _) = self
+            .recorded
+            .wait_timeout_while(events, timeout, |events| find(events).is_none())
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        find(&events)
     }
 }
 
+impl FieldObserver for RecordingObserver {
+    fn on_event(&self, event: &FieldEvent) {
+        self.events.lock().push((event.tick(), event.clone()));
+        #[cfg(feature = "std")]
+        self.recorded.notify_all();
+    }
+}
+
+/// What a bounded observer does when its queue is full.
+///
+/// Observers must never block the field's write path, so a full queue
+/// always loses an event - this chooses which one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DropPolicy {
+    /// Discard the oldest queued event to make room for the new one.
+    DropOldest,
+    /// Discard the new event and keep the backlog.
+    #[default]
+    DropNewest,
+}
+
 /// Configuration for what triggers notifications
+///
+/// With the `serde` feature the whole monitoring setup can be kept in a
+/// config file and loaded with [`TemporalField::apply_triggers`]. Missing
+/// fields take their defaults.
+///
+/// [`TemporalField::apply_triggers`]: crate::TemporalField::apply_triggers
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct TriggerConfig {
     /// Regions to monitor (empty = monitor all dims as one region)
     pub regions: Vec<MonitoredRegion>,
+    /// Regions whose active state is derived from other regions
+    pub composites: Vec<CompositeRegion>,
+    /// Ordered region-activation sequences to detect
+    pub sequences: Vec<SequenceTemplate>,
+    /// Region pairs checked for pattern similarity
+    pub correlations: Vec<CorrelationMonitor>,
     /// Minimum regions active for convergence event
     pub convergence_threshold: usize,
+    /// Minimum total weighted energy for convergence event (0 = no gate)
+    pub convergence_min_energy: u64,
+    /// Named region subsets that converge independently
+    pub convergence_groups: Vec<ConvergenceGroup>,
+    /// Fire a Tick event at the end of every tick
+    pub emit_tick_events: bool,
+    /// Fire ExpectationMet when an expected activation arrives in time
+    pub emit_expectation_met: bool,
+    /// Check thresholds against the new frame whenever the write head advances
+    pub check_on_advance: bool,
+    /// Capacity of the polling event log (0 = disabled)
+    pub event_log_capacity: usize,
+    /// Suppress repeats of the same event type for the same region within
+    /// one tick
+    pub dedup_per_tick: bool,
+    /// Ticks a region still counts toward convergence after its RegionActive
+    /// (0 = only regions active right now)
+    pub coincidence_window: u64,
+    /// Whole-frame `(on_threshold, off_threshold)` for GlobalActive/GlobalQuiet
+    /// (None = disabled)
+    pub global_threshold: Option<(u64, u64)>,
+    /// Lateral inhibition links between monitored regions
+    pub inhibitions: Vec<Inhibition>,
 }
 
 /// Default hysteresis gap as percentage (20 = 20%).
 /// off_threshold = on_threshold * (100 - gap) / 100
 pub const DEFAULT_HYSTERESIS_GAP: u8 = 20;
 
+/// Default minimum prototype similarity for Recognized, per mille.
+pub const DEFAULT_RECOGNITION_PERMILLE: u16 = 800;
+
+#[cfg(feature = "serde")]
+fn default_recognition_permille() -> u16 {
+    DEFAULT_RECOGNITION_PERMILLE
+}
+
+/// Default frame-to-frame similarity, per mille, above which a habituating
+/// region adapts.
+pub const DEFAULT_HABITUATION_PERMILLE: u16 = 900;
+
+/// How a region adapts to a constant stimulus.
+///
+/// The field keeps an adaptation level (0..=255) per region. Each tick it
+/// rises by `rise` while the region's content is at least
+/// `similarity_permille` similar to the previous tick's, and falls by
+/// `recovery` otherwise. Thresholds see `energy × (255 − adaptation) / 255`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Habituation {
+    /// Adaptation gained per tick of unchanged content
+    pub rise: u8,
+    /// Adaptation lost per tick of changed (or empty) content
+    pub recovery: u8,
+    /// Similarity to the previous tick, per mille, that counts as unchanged
+    pub similarity_permille: u16,
+}
+
+/// When a region's content counts as novel.
+///
+/// Distance is Σ|current − mean| over the region's dims, with the mean
+/// taken over the `window` frames before the current one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NoveltyThreshold {
+    /// Frames averaged into the reference mean
+    pub window: usize,
+    /// Distance above which Novelty fires
+    pub on_distance: u64,
+    /// Distance below which Novelty re-arms
+    pub off_distance: u64,
+}
+
+/// A named pattern a monitored region is compared against when it goes
+/// active.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Prototype {
+    /// Name reported in Recognized events
+    pub name: String,
+    /// One signal per region dim, in the region's dim order
+    pub signals: Vec<Signal>,
+}
+
+/// Adaptive threshold that follows a region's running energy baseline.
+///
+/// The baseline is an integer exponential moving average updated once per
+/// tick: `baseline = (baseline × retention + energy × (255 − retention)) / 255`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AdaptiveThreshold {
+    /// Baseline retention per tick (255 = frozen, 230 ≈ slow, 128 = fast)
+    pub retention: u8,
+    /// Effective on_threshold as percent of the baseline (150 = 1.5×)
+    pub multiplier_percent: u16,
+}
+
+impl AdaptiveThreshold {
+    /// Fold one energy sample into the baseline.
+    pub fn update(&self, baseline: u64, energy: u64) -> u64 {
+        let retention = self.retention as u128;
+        ((baseline as u128 * retention + energy as u128 * (255 - retention)) / 255) as u64
+    }
+}
+
+/// How a region's dims are reduced to the energy its thresholds see.
+///
+/// All metrics work on effective values (p×m×k, ±65,025).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EnergyMetric {
+    /// Σ(magnitude²), polarity ignored
+    #[default]
+    MagnitudeSq,
+    /// Σ|magnitude|, polarity ignored
+    L1,
+    /// |Σ(polarity × magnitude)|: negative signals inhibit positive ones
+    SignedNet {
+        /// Square the net sum (comparable in scale to MagnitudeSq)
+        squared: bool,
+    },
+}
+
+impl EnergyMetric {
+    /// Measure the dims of `spec` in one frame (saturating at u64::MAX).
+    pub fn measure<'a>(&self, frame: impl Into<FrameRef<'a>>, spec: &RegionSpec) -> u64 {
+        let frame = frame.into();
+        match self {
+            EnergyMetric::MagnitudeSq => frame.indices_energy(spec),
+            EnergyMetric::L1 => frame.indices_l1(spec) as u64,
+            EnergyMetric::SignedNet { squared } => {
+                let net = frame.indices_signed_sum(spec).unsigned_abs();
+                if *squared {
+                    net.saturating_mul(net)
+                } else {
+                    net
+                }
+            }
+        }
+    }
+
+    /// One dim's term in this metric's running sum.
+    ///
+    /// Summing terms over a region and passing the sum to
+    /// [`finish`](Self::finish) gives [`measure`](Self::measure), so a sum
+    /// can be kept up to date by adding and removing single dims.
+    pub(crate) fn term(&self, signal: Signal) -> i128 {
+        match self {
+            EnergyMetric::MagnitudeSq => {
+                let eff = signal.effective_magnitude() as i128;
+                eff * eff
+            }
+            EnergyMetric::L1 => signal.effective_magnitude() as i128,
+            EnergyMetric::SignedNet { .. } => signal.current() as i128,
+        }
+    }
+
+    /// Energy from a sum of [`term`](Self::term)s, saturating like
+    /// [`measure`](Self::measure).
+    pub(crate) fn finish(&self, sum: i128) -> u64 {
+        match self {
+            EnergyMetric::MagnitudeSq => sum.clamp(0, u64::MAX as i128) as u64,
+            EnergyMetric::L1 => sum.clamp(0, u32::MAX as i128) as u64,
+            EnergyMetric::SignedNet { squared } => {
+                let net = sum.clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+                let net = net.unsigned_abs();
+                if *squared {
+                    net.saturating_mul(net)
+                } else {
+                    net
+                }
+            }
+        }
+    }
+}
+
+/// Energy of one dim at full-scale magnitude (255, multiplier 1): 255².
+const FULL_SCALE_DIM_ENERGY: u64 = 255 * 255;
+
+/// `permille`/1000 of the full-scale energy of `dims` dims.
+fn relative_threshold(dims: usize, permille: u16) -> u64 {
+    (dims as u64 * FULL_SCALE_DIM_ENERGY).saturating_mul(permille.min(1000) as u64) / 1000
+}
+
 /// A region being monitored for activity with hysteresis thresholds.
 ///
 /// ## Hysteresis
@@ -113,11 +1254,17 @@ pub const DEFAULT_HYSTERESIS_GAP: u8 = 20;
 /// For 64 dims with all magnitudes at 128: 64 × 128² = 1,048,576
 /// For 64 dims with all magnitudes at 255: 64 × 255² = 4,161,600
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MonitoredRegion {
     /// Name for identification
     pub name: String,
-    /// Dimension range
+    /// Dimension range (bounding range when `spec` is set)
     pub range: Range<usize>,
+    /// Dims watched when not every dim of `range` (None = contiguous `range`)
+    pub spec: Option<RegionSpec>,
+    /// Grid rectangle as (rows, cols), resolved into `spec` against the
+    /// field's grid when the region is monitored
+    pub rect: Option<(Range<usize>, Range<usize>)>,
     /// Energy threshold to enter active state (higher threshold)
     /// Energy = sum of squared effective magnitudes (p×m×k)
     pub on_threshold: u64,
@@ -125,6 +1272,44 @@ pub struct MonitoredRegion {
     pub off_threshold: u64,
     /// Weight for convergence calculation (100 = 1.0×)
     pub weight: u8,
+    /// Minimum energy rise and fall around a local maximum for a Peak event
+    pub min_prominence: u64,
+    /// Per-tick energy rise above which an Onset fires (None = no onset check)
+    pub onset_threshold: Option<u64>,
+    /// Track a running baseline and raise the thresholds above it
+    /// (None = static thresholds)
+    pub adaptive: Option<AdaptiveThreshold>,
+    /// on_threshold as per mille of full-scale energy (None = absolute threshold)
+    pub relative_permille: Option<u16>,
+    /// Saturated fraction (per mille) above which a SaturationWarning fires
+    /// (None = no saturation check)
+    pub saturation_permille: Option<u16>,
+    /// Ticks of energy history kept for `region_stats` (None = no stats)
+    pub stats_window: Option<usize>,
+    /// Frames summed into the energy checked against the thresholds
+    /// (None = current frame only)
+    pub energy_window: Option<usize>,
+    /// `(on, off)` occupancy per mille for RegionDense (None = no density check)
+    pub density_permille: Option<(u16, u16)>,
+    /// How dims are reduced to energy (default Σ magnitude²)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub metric: EnergyMetric,
+    /// Include the activation centroid in RegionActive
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub report_centroid: bool,
+    /// Track state without firing events or joining convergence
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub silent: bool,
+    /// Stored patterns checked when the region goes active
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub prototypes: Vec<Prototype>,
+    /// Minimum prototype similarity per mille for Recognized (default 800)
+    #[cfg_attr(feature = "serde", serde(default = "default_recognition_permille"))]
+    pub recognition_permille: u16,
+    /// Distance from the recent mean that fires Novelty (None = no novelty check)
+    pub novelty: Option<NoveltyThreshold>,
+    /// Adaptation to constant input (None = no habituation)
+    pub habituation: Option<Habituation>,
 }
 
 impl MonitoredRegion {
@@ -138,12 +1323,110 @@ impl MonitoredRegion {
         Self {
             name: name.into(),
             range,
+            spec: None,
+            rect: None,
             on_threshold: threshold,
             off_threshold: threshold * (100 - DEFAULT_HYSTERESIS_GAP as u64) / 100,
             weight: 100,
+            min_prominence: 0,
+            onset_threshold: None,
+            adaptive: None,
+            relative_permille: None,
+            saturation_permille: None,
+            stats_window: None,
+            energy_window: None,
+            density_permille: None,
+            metric: EnergyMetric::MagnitudeSq,
+            report_centroid: false,
+            silent: false,
+            prototypes: Vec::new(),
+            recognition_permille: DEFAULT_RECOGNITION_PERMILLE,
+            novelty: None,
+            habituation: None,
         }
     }
 
+    /// Create a monitored region whose threshold scales with its width.
+    ///
+    /// on_threshold is `permille`/1000 of the full-scale energy
+    /// `range.len() × 255²`, so the same permille means the same relative
+    /// activity for a 32-dim and a 64-dim region. Default hysteresis applies.
+    pub fn new_relative(name: impl Into<String>, range: Range<usize>, permille: u16) -> Self {
+        let threshold = relative_threshold(range.len(), permille);
+        let mut region = Self::new(name, range, threshold);
+        region.relative_permille = Some(permille);
+        region
+    }
+
+    /// Change the dimension range.
+    ///
+    /// For relative regions the thresholds are recomputed for the new width,
+    /// keeping the on/off ratio. Absolute thresholds are left unchanged.
+    pub fn set_range(&mut self, range: Range<usize>) {
+        if let Some(permille) = self.relative_permille {
+            let on_threshold = relative_threshold(range.len(), permille);
+            self.off_threshold = (self.off_threshold as u128 * on_threshold as u128)
+                .checked_div(self.on_threshold as u128)
+                .map(|off| off as u64)
+                .unwrap_or(on_threshold * (100 - DEFAULT_HYSTERESIS_GAP as u64) / 100);
+            self.on_threshold = on_threshold;
+        }
+        self.range = range;
+        self.spec = None;
+        self.rect = None;
+    }
+
+    /// Create a monitored region over an arbitrary set of dims.
+    ///
+    /// Energy is Σ(magnitude²) over just the listed dims; `range` is set to
+    /// their bounding range. Default hysteresis applies.
+    ///
+    /// ```rust
+    /// use temporal_field::{MonitoredRegion, RegionSpec};
+    ///
+    /// let left_ear = RegionSpec::Strided { start: 0, step: 2, count: 64 };
+    /// let region = MonitoredRegion::over("left_ear", left_ear, 100_000);
+    /// assert_eq!(region.range, 0..127);
+    /// assert_eq!(region.width(), 64);
+    /// ```
+    pub fn over(name: impl Into<String>, spec: impl Into<RegionSpec>, threshold: u64) -> Self {
+        let spec = spec.into();
+        let mut region = Self::new(name, spec.bounds(), threshold);
+        if !matches!(spec, RegionSpec::Contiguous(_)) {
+            region.spec = Some(spec);
+        }
+        region
+    }
+
+    /// Create a monitored region over a rectangle of the field's grid.
+    ///
+    /// Rows and cols index a field configured with
+    /// [`FieldConfig::with_grid`](crate::FieldConfig::with_grid); the
+    /// rectangle is expanded to its dims when passed to `monitor_region`.
+    /// Default hysteresis applies.
+    pub fn rect(
+        name: impl Into<String>,
+        rows: Range<usize>,
+        cols: Range<usize>,
+        threshold: u64,
+    ) -> Self {
+        let mut region = Self::new(name, 0..0, threshold);
+        region.rect = Some((rows, cols));
+        region
+    }
+
+    /// Get the dims this region watches.
+    pub fn region_spec(&self) -> RegionSpec {
+        self.spec
+            .clone()
+            .unwrap_or_else(|| RegionSpec::Contiguous(self.range.clone()))
+    }
+
+    /// Number of dims this region watches.
+    pub fn width(&self) -> usize {
+        self.spec.as_ref().map_or(self.range.len(), RegionSpec::len)
+    }
+
     /// Create a monitored region with explicit hysteresis thresholds.
     ///
     /// # Arguments
@@ -167,9 +1450,26 @@ impl MonitoredRegion {
         Self {
             name: name.into(),
             range,
+            spec: None,
+            rect: None,
             on_threshold,
             off_threshold,
             weight: 100,
+            min_prominence: 0,
+            onset_threshold: None,
+            adaptive: None,
+            relative_permille: None,
+            saturation_permille: None,
+            stats_window: None,
+            energy_window: None,
+            density_permille: None,
+            metric: EnergyMetric::MagnitudeSq,
+            report_centroid: false,
+            silent: false,
+            prototypes: Vec::new(),
+            recognition_permille: DEFAULT_RECOGNITION_PERMILLE,
+            novelty: None,
+            habituation: None,
         }
     }
 
@@ -188,21 +1488,315 @@ impl MonitoredRegion {
         self
     }
 
+    /// Set minimum peak prominence (energy delta).
+    ///
+    /// A Peak fires only when energy rose at least this much from the
+    /// preceding trough and then fell at least this much from the peak.
+    pub fn with_prominence(mut self, min_prominence: u64) -> Self {
+        self.min_prominence = min_prominence;
+        self
+    }
+
+    /// Fire Onset when energy rises by more than `rate_threshold` in one tick.
+    ///
+    /// Only rising deltas count, and the region does not need to reach
+    /// on_threshold.
+    pub fn with_onset(mut self, rate_threshold: u64) -> Self {
+        self.onset_threshold = Some(rate_threshold);
+        self
+    }
+
+    /// Warn when more than `permille`/1000 of the region's dims saturate.
+    ///
+    /// A dim is saturated when its effective magnitude sits at the ±65,025
+    /// ceiling, where further writes are clipped.
+    pub fn with_saturation_warning(mut self, permille: u16) -> Self {
+        self.saturation_permille = Some(permille);
+        self
+    }
+
+    /// Keep the last `window` ticks of energy for
+    /// [`region_stats`](crate::TemporalField::region_stats).
+    pub fn with_stats(mut self, window: usize) -> Self {
+        self.stats_window = Some(window);
+        self
+    }
+
+    /// Compare thresholds against energy summed over the current frame and
+    /// the `frames − 1` before it, instead of the current frame alone.
+    ///
+    /// For slow modalities whose activity is spread across frames. Peak,
+    /// onset, baseline, stats and calibration all see the windowed energy.
+    pub fn with_window(mut self, frames: usize) -> Self {
+        self.energy_window = Some(frames);
+        self
+    }
+
+    /// Fire RegionDense when more than `on_permille`/1000 of the region's
+    /// dims are nonzero.
+    ///
+    /// Re-arms below `on_permille` minus the default 20% hysteresis gap.
+    /// Unlike energy, occupancy tells broad low-level activation apart from
+    /// a few saturated dims.
+    pub fn with_density(mut self, on_permille: u16) -> Self {
+        let off = on_permille as u32 * (100 - DEFAULT_HYSTERESIS_GAP as u32) / 100;
+        self.density_permille = Some((on_permille, off as u16));
+        self
+    }
+
+    /// Measure energy with `metric` instead of Σ(magnitude²).
+    ///
+    /// Thresholds, peaks, onsets, baselines, stats and calibration all use
+    /// the metric's units. Relative thresholds assume MagnitudeSq.
+    ///
+    /// ```rust
+    /// use temporal_field::{EnergyMetric, MonitoredRegion};
+    ///
+    /// // Inhibitory (negative) signals cancel excitatory ones
+    /// let region = MonitoredRegion::new("motor", 0..32, 10_000)
+    ///     .with_metric(EnergyMetric::SignedNet { squared: true });
+    /// assert_eq!(region.metric, EnergyMetric::SignedNet { squared: true });
+    /// ```
+    pub fn with_metric(mut self, metric: EnergyMetric) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    /// Report where in the region activation is concentrated: RegionActive
+    /// carries the current frame's centroid (see
+    /// [`FieldVector::range_centroid`]).
+    pub fn with_centroid(mut self) -> Self {
+        self.report_centroid = true;
+        self
+    }
+
+    /// Track the region without emitting events (a shadow region).
+    ///
+    /// The hysteresis state machine still runs, so `is_region_active`,
+    /// `region_stats` and calibration work as usual, but the region fires
+    /// nothing of its own, and is left out of convergence and sequences.
+    pub fn silent(mut self) -> Self {
+        self.silent = true;
+        self
+    }
+
+    /// Fire Novelty when the region's content moves more than `on` away
+    /// from its mean over the previous `window` frames.
+    ///
+    /// Re-arms once the distance drops below `off`. Distances are in
+    /// effective values (p×m×k), so amplified signals count at full size.
+    pub fn with_novelty(mut self, window: usize, on: u64, off: u64) -> Self {
+        debug_assert!(off <= on, "off distance ({}) must be <= on ({})", off, on);
+        self.novelty = Some(NoveltyThreshold {
+            window,
+            on_distance: on,
+            off_distance: off,
+        });
+        self
+    }
+
+    /// Let the region stop responding to a constant stimulus.
+    ///
+    /// Adaptation rises by `rise` each tick the content stays the same and
+    /// falls by `recovery` each tick it changes, scaling the energy the
+    /// thresholds see (see [`Habituation`]). RegionActive and RegionQuiet
+    /// report the scaled energy, so a held input eventually goes quiet.
+    ///
+    /// ```rust
+    /// use temporal_field::MonitoredRegion;
+    ///
+    /// // Saturates after 4 ticks of unchanged input, recovers over 8
+    /// let region = MonitoredRegion::new("touch", 0..16, 1000).with_habituation(64, 32);
+    /// assert_eq!(region.habituation.unwrap().rise, 64);
+    /// ```
+    pub fn with_habituation(mut self, rise: u8, recovery: u8) -> Self {
+        self.habituation = Some(Habituation {
+            rise,
+            recovery,
+            similarity_permille: DEFAULT_HABITUATION_PERMILLE,
+        });
+        self
+    }
+
+    /// Set the minimum prototype similarity, per mille, for Recognized.
+    ///
+    /// Prototypes are added with
+    /// [`TemporalField::add_prototype`](crate::TemporalField::add_prototype).
+    pub fn with_recognition(mut self, permille: u16) -> Self {
+        self.recognition_permille = permille;
+        self
+    }
+
+    /// Make the thresholds follow the region's running energy baseline.
+    ///
+    /// Each tick the baseline moves toward the current energy by
+    /// `(255 − retention)/255`. The effective on_threshold is
+    /// `baseline × multiplier_percent / 100`, never below the static
+    /// on_threshold; off_threshold keeps the same on/off ratio.
+    pub fn with_adaptive(mut self, retention: u8, multiplier_percent: u16) -> Self {
+        self.adaptive = Some(AdaptiveThreshold {
+            retention,
+            multiplier_percent,
+        });
+        self
+    }
+
+    /// Get `(on_threshold, off_threshold)` in effect for a baseline.
+    ///
+    /// Static regions ignore the baseline.
+    pub fn effective_thresholds(&self, baseline: u64) -> (u64, u64) {
+        let Some(adaptive) = self.adaptive else {
+            return (self.on_threshold, self.off_threshold);
+        };
+        let raised = (baseline as u128 * adaptive.multiplier_percent as u128 / 100) as u64;
+        if raised <= self.on_threshold {
+            return (self.on_threshold, self.off_threshold);
+        }
+        let off = (self.off_threshold as u128 * raised as u128)
+            .checked_div(self.on_threshold as u128)
+            .map_or(raised, |off| off as u64);
+        (raised, off)
+    }
+
     /// Get the hysteresis gap as percentage.
     pub fn hysteresis_gap(&self) -> u8 {
-        if self.on_threshold > 0 {
-            (100 - (self.off_threshold * 100 / self.on_threshold)) as u8
-        } else {
-            0
+        (self.off_threshold * 100)
+            .checked_div(self.on_threshold)
+            .map(|pct| (100 - pct) as u8)
+            .unwrap_or(0)
+    }
+}
+
+/// An ordered sequence of region activations, e.g. onset → sustain.
+///
+/// Each step names a monitored region. The sequence matches when the
+/// regions fire RegionActive in step order, each within `max_gap_ticks` of
+/// the previous step. Activations of regions not in the template are noise
+/// and ignored.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SequenceTemplate {
+    /// Name for identification
+    pub name: String,
+    /// Region names in the order they must activate
+    pub steps: Vec<String>,
+    /// Maximum ticks between consecutive steps before the match resets
+    pub max_gap_ticks: u64,
+    /// On an out-of-order step, restart matching from that activation
+    /// (true) instead of discarding it (false)
+    pub restart_on_mismatch: bool,
+}
+
+impl SequenceTemplate {
+    /// Create a sequence template. Out-of-order steps reset the match.
+    pub fn new<S: Into<String>>(
+        name: impl Into<String>,
+        steps: impl IntoIterator<Item = S>,
+        max_gap_ticks: u64,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            steps: steps.into_iter().map(Into::into).collect(),
+            max_gap_ticks,
+            restart_on_mismatch: false,
         }
     }
+
+    /// Restart matching from an out-of-order step instead of discarding it.
+    pub fn with_restart_on_mismatch(mut self, restart: bool) -> Self {
+        self.restart_on_mismatch = restart;
+        self
+    }
+}
+
+/// A named subset of monitored regions with its own convergence threshold.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConvergenceGroup {
+    /// Name reported in the group's Convergence events
+    pub name: String,
+    /// Names of the member regions
+    pub regions: Vec<String>,
+    /// Minimum active members for the group to converge
+    pub min_active: usize,
+}
+
+/// A region that is active while enough of its child regions are.
+///
+/// Children are monitored regions or previously defined composites. The
+/// composite fires its own RegionActive/RegionQuiet and stands in for its
+/// children in field-wide convergence.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CompositeRegion {
+    /// Name for identification
+    pub name: String,
+    /// Names of the child regions
+    pub children: Vec<String>,
+    /// Minimum active children for the composite to be active
+    pub min_children: usize,
+}
+
+/// A pair of equal-length monitored regions checked for similar patterns.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CorrelationMonitor {
+    /// Name of the first region
+    pub a: String,
+    /// Name of the second region
+    pub b: String,
+    /// Similarity per mille above which a Correlated event fires
+    pub similarity_threshold: u16,
+}
+
+/// When an inhibition link suppresses its target.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InhibitionMode {
+    /// Once, when the source becomes active
+    #[default]
+    OnActivate,
+    /// At every evaluation (each write and tick) while the source is active
+    WhileActive,
+}
+
+/// Lateral inhibition: an active source region suppresses a target region.
+///
+/// The target's signals are scaled by `(255 − strength)/255` before its
+/// threshold is checked, so a strength of 255 silences it. Links into the
+/// same target compose multiplicatively.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Inhibition {
+    /// Name of the inhibiting region
+    pub source: String,
+    /// Name of the inhibited region
+    pub target: String,
+    /// Suppression strength (255 = full suppression)
+    pub strength: u8,
+    /// When the suppression is applied
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mode: InhibitionMode,
 }
 
 impl Default for TriggerConfig {
     fn default() -> Self {
         Self {
             regions: Vec::new(),
+            composites: Vec::new(),
+            sequences: Vec::new(),
+            correlations: Vec::new(),
             convergence_threshold: 2,
+            convergence_min_energy: 0,
+            convergence_groups: Vec::new(),
+            emit_tick_events: false,
+            emit_expectation_met: false,
+            check_on_advance: false,
+            event_log_capacity: 0,
+            dedup_per_tick: false,
+            coincidence_window: 0,
+            global_threshold: None,
+            inhibitions: Vec::new(),
         }
     }
 }
@@ -211,6 +1805,98 @@ impl Default for TriggerConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_event_mask() {
+        let mask = EventMask::CONVERGENCE | EventMask::PEAK;
+        assert!(mask.contains(&EventMask::CONVERGENCE));
+        assert!(mask.contains(&EventMask::PEAK));
+        assert!(!mask.contains(&EventMask::REGION_ACTIVE));
+        assert!(EventMask::ALL.contains(&mask));
+        assert!(EventMask::NONE.is_empty());
+        assert_eq!(mask.for_region("a").region.as_deref(), Some("a"));
+    }
+
+    #[test]
+    fn test_recording_observer() {
+        let recorder = RecordingObserver::new();
+        let active = FieldEvent::RegionActive {
+            name: "a".into(),
+            region: 0..8,
+            spec: RegionSpec::Contiguous(0..8),
+            energy: 100,
+            metric: EnergyMetric::MagnitudeSq,
+            threshold: 50,
+            baseline: 0,
+            centroid: None,
+            tick: 3,
+        };
+        recorder.on_event(&active);
+        recorder.on_event(&FieldEvent::Tick {
+            tick: 4,
+            total_energy: 100,
+        });
+
+        assert_eq!(recorder.len(), 2);
+        assert_eq!(recorder.events()[0], (3, active));
+        assert_eq!(recorder.count_of(&EventMask::REGION_ACTIVE), 1);
+        assert_eq!(recorder.count_of(&EventMask::ALL), 2);
+        assert_eq!(recorder.count_of(&EventMask::CONVERGENCE), 0);
+
+        recorder.clear();
+        assert!(recorder.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_recording_observer_wait_for() {
+        let recorder = Arc::new(RecordingObserver::new());
+        let r = recorder.clone();
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            r.on_event(&FieldEvent::Tick {
+                tick: 7,
+                total_energy: 0,
+            });
+        });
+
+        let found = recorder.wait_for(&EventMask::TICK, Duration::from_secs(5));
+        assert_eq!(found.map(|(tick, _)| tick), Some(7));
+        assert!(recorder
+            .wait_for(&EventMask::PEAK, Duration::from_millis(10))
+            .is_none());
+        writer.join().unwrap();
+    }
+
+    #[test]
+    fn test_fn_mut_observer_collects_history() {
+        let mut field = crate::TemporalField::new(crate::FieldConfig::new(8, 10, 128));
+        field
+            .monitor_region(MonitoredRegion::new("a", 0..8, 10_000))
+            .unwrap();
+
+        // Plain Vec captured by value; snapshots go out over a channel
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut history = Vec::new();
+        field.subscribe(Arc::new(FnMutObserver::new(move |event: &FieldEvent| {
+            history.push(event.mask());
+            let _ = tx.send(history.clone());
+        })));
+
+        let signals = [ternary_signal::Signal::positive_amplified(100, 1); 8];
+        field.write_region(&signals, 0..8);
+        field.tick_n(4);
+
+        let history = rx.try_iter().last().unwrap();
+        assert_eq!(
+            history,
+            vec![
+                EventMask::REGION_ACTIVE,
+                EventMask::REGION_QUIET,
+                EventMask::PEAK
+            ]
+        );
+    }
+
     #[test]
     fn test_default_hysteresis() {
         // Default gap is 20%
@@ -235,9 +1921,93 @@ mod tests {
         assert_eq!(region.off_threshold, 700); // 1000 * 70 / 100
     }
 
+    #[test]
+    fn test_with_prominence() {
+        let region = MonitoredRegion::new("test", 0..32, 1000);
+        assert_eq!(region.min_prominence, 0);
+        let region = region.with_prominence(500);
+        assert_eq!(region.min_prominence, 500);
+    }
+
     #[test]
     fn test_with_weight() {
         let region = MonitoredRegion::new("test", 0..32, 1000).with_weight(150);
         assert_eq!(region.weight, 150);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_trigger_config_json_round_trip() {
+        let mut config = TriggerConfig {
+            convergence_threshold: 3,
+            ..Default::default()
+        };
+        config.regions = vec![
+            MonitoredRegion::new("audio", 0..64, 100_000).with_gap(35),
+            MonitoredRegion::with_hysteresis("text", 64..128, 90_001, 12_345).with_weight(150),
+        ];
+        config
+            .sequences
+            .push(SequenceTemplate::new("grasp", ["audio", "text"], 5));
+
+        let json = serde_json::to_string(&config).unwrap();
+        let loaded: TriggerConfig = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.convergence_threshold, 3);
+        assert_eq!(loaded.sequences[0].steps, vec!["audio", "text"]);
+        for (a, b) in config.regions.iter().zip(&loaded.regions) {
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.range, b.range);
+            assert_eq!(a.on_threshold, b.on_threshold);
+            assert_eq!(a.off_threshold, b.off_threshold);
+            assert_eq!(a.hysteresis_gap(), b.hysteresis_gap());
+            assert_eq!(a.weight, b.weight);
+        }
+
+        // Omitted fields fall back to defaults
+        let partial: TriggerConfig = serde_json::from_str(r#"{"emit_tick_events":true}"#).unwrap();
+        assert!(partial.emit_tick_events);
+        assert_eq!(partial.convergence_threshold, 2);
+    }
+
+    #[test]
+    fn test_relative_threshold_scales_with_width() {
+        let narrow = MonitoredRegion::new_relative("narrow", 0..32, 250);
+        let wide = MonitoredRegion::new_relative("wide", 0..64, 250);
+        assert_eq!(narrow.on_threshold, 32 * 255 * 255 / 4);
+        assert_eq!(wide.on_threshold, 2 * narrow.on_threshold);
+        assert_eq!(wide.hysteresis_gap(), DEFAULT_HYSTERESIS_GAP);
+
+        // Editing the range recomputes relative thresholds only
+        let mut resized = narrow.clone().with_gap(30);
+        resized.set_range(0..64);
+        assert_eq!(resized.on_threshold, wide.on_threshold);
+        assert_eq!(resized.hysteresis_gap(), 30);
+
+        let mut absolute = MonitoredRegion::new("abs", 0..32, 1000);
+        absolute.set_range(0..64);
+        assert_eq!(absolute.on_threshold, 1000);
+
+        // Permille above 1000 is clamped to full scale
+        let full = MonitoredRegion::new_relative("full", 0..8, u16::MAX);
+        assert_eq!(full.on_threshold, 8 * 255 * 255);
+    }
+
+    #[test]
+    fn test_pool_rows_matches_pool() {
+        let rows: Vec<Vec<Signal>> = (0..5)
+            .map(|r| {
+                (0..7)
+                    .map(|c| Signal::from_current((r * 37 + c * 91) % 301 - 150))
+                    .collect()
+            })
+            .collect();
+        for mode in [PoolMode::Max, PoolMode::Mean, PoolMode::Sum] {
+            let by_column: Vec<Signal> = (0..7)
+                .map(|c| mode.pool(rows.iter().map(|row| row[c])))
+                .collect();
+            assert_eq!(mode.pool_rows(rows.iter().map(Vec::as_slice), 7), by_column);
+            assert_eq!(mode.pool_rows([], 3), vec![Signal::ZERO; 3]);
+        }
+    }
 }
@@ -0,0 +1,183 @@
+//! Region specs - which dims a region covers
+//!
+//! Most regions are a contiguous `Range`. Interleaved feature layouts are
+//! not: a stereo layout may put the left ear on even dims and the right ear
+//! on odd ones. `RegionSpec` covers both, and everything that takes a range
+//! has an index-set counterpart built on it.
+//!
+//! ```rust
+//! use temporal_field::RegionSpec;
+//!
+//! let left = RegionSpec::Strided { start: 0, step: 2, count: 64 };
+//! assert_eq!(left.len(), 64);
+//! assert_eq!(left.bounds(), 0..127);
+//! assert!(left.contains(126));
+//! assert!(!left.contains(1));
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Set of dims covered by a region.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RegionSpec {
+    /// Every dim in a range
+    Contiguous(Range<usize>),
+    /// Listed dims, in order
+    Indices(Vec<usize>),
+    /// `count` dims starting at `start`, `step` apart
+    Strided {
+        /// First dim
+        start: usize,
+        /// Distance between consecutive dims
+        step: usize,
+        /// Number of dims
+        count: usize,
+    },
+}
+
+impl RegionSpec {
+    /// Dims of a rectangle on a row-major grid `grid_cols` wide.
+    ///
+    /// Not checked against the grid; [`TemporalField`](crate::TemporalField)
+    /// validates rectangles before using them.
+    pub fn rect(grid_cols: usize, rows: Range<usize>, cols: Range<usize>) -> Self {
+        RegionSpec::Indices(
+            rows.flat_map(|row| cols.clone().map(move |col| row * grid_cols + col))
+                .collect(),
+        )
+    }
+
+    /// Number of dims covered (listed duplicates count twice).
+    pub fn len(&self) -> usize {
+        match self {
+            RegionSpec::Contiguous(range) => range.len(),
+            RegionSpec::Indices(indices) => indices.len(),
+            RegionSpec::Strided { count, .. } => *count,
+        }
+    }
+
+    /// Check if no dims are covered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate the covered dims in order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        let (range, indices, strided) = match self {
+            RegionSpec::Contiguous(range) => (Some(range.clone()), None, None),
+            RegionSpec::Indices(indices) => (None, Some(indices.iter().copied()), None),
+            RegionSpec::Strided { start, step, count } => {
+                let (start, step) = (*start, *step);
+                (None, None, Some((0..*count).map(move |i| start + i * step)))
+            }
+        };
+        range
+            .into_iter()
+            .flatten()
+            .chain(indices.into_iter().flatten())
+            .chain(strided.into_iter().flatten())
+    }
+
+    /// Smallest range containing every covered dim (empty for empty specs).
+    pub fn bounds(&self) -> Range<usize> {
+        match self {
+            RegionSpec::Contiguous(range) => range.clone(),
+            _ => {
+                let min = self.iter().min();
+                let max = self.iter().max();
+                match (min, max) {
+                    (Some(min), Some(max)) => min..max + 1,
+                    _ => 0..0,
+                }
+            }
+        }
+    }
+
+    /// Check if a dim is covered.
+    pub fn contains(&self, index: usize) -> bool {
+        match self {
+            RegionSpec::Contiguous(range) => range.contains(&index),
+            RegionSpec::Indices(indices) => indices.contains(&index),
+            RegionSpec::Strided { start, step, count } => {
+                let Some(offset) = index.checked_sub(*start) else {
+                    return false;
+                };
+                match offset.checked_div(*step) {
+                    Some(i) => offset.is_multiple_of(*step) && i < *count,
+                    None => offset == 0 && *count > 0,
+                }
+            }
+        }
+    }
+
+    /// Check if any covered dim falls inside `range`.
+    pub fn overlaps(&self, range: &Range<usize>) -> bool {
+        match self {
+            RegionSpec::Contiguous(r) => r.start < range.end && range.start < r.end,
+            _ => self.iter().any(|i| range.contains(&i)),
+        }
+    }
+}
+
+impl From<Range<usize>> for RegionSpec {
+    fn from(range: Range<usize>) -> Self {
+        RegionSpec::Contiguous(range)
+    }
+}
+
+impl From<Vec<usize>> for RegionSpec {
+    fn from(indices: Vec<usize>) -> Self {
+        RegionSpec::Indices(indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_spec_shapes_agree() {
+        let contiguous = RegionSpec::from(4..8);
+        let indices = RegionSpec::from(vec![4, 5, 6, 7]);
+        let strided = RegionSpec::Strided {
+            start: 4,
+            step: 1,
+            count: 4,
+        };
+        for spec in [&contiguous, &indices, &strided] {
+            assert_eq!(spec.len(), 4);
+            assert_eq!(spec.bounds(), 4..8);
+            assert_eq!(spec.iter().collect::<Vec<_>>(), vec![4, 5, 6, 7]);
+            assert!(spec.contains(7) && !spec.contains(8));
+            assert!(spec.overlaps(&(7..20)) && !spec.overlaps(&(8..20)));
+        }
+
+        let odd = RegionSpec::Strided {
+            start: 1,
+            step: 2,
+            count: 3,
+        };
+        assert_eq!(odd.iter().collect::<Vec<_>>(), vec![1, 3, 5]);
+        assert_eq!(odd.bounds(), 1..6);
+        assert!(odd.contains(5) && !odd.contains(4) && !odd.contains(7));
+        assert!(!odd.overlaps(&(2..3)));
+
+        // Rows 1..3, cols 2..5 of a 4 × 8 grid
+        let rect = RegionSpec::rect(8, 1..3, 2..5);
+        assert_eq!(
+            rect.iter().collect::<Vec<_>>(),
+            vec![10, 11, 12, 18, 19, 20]
+        );
+        assert_eq!(rect.bounds(), 10..21);
+
+        let empty = RegionSpec::Indices(Vec::new());
+        assert!(empty.is_empty());
+        assert_eq!(empty.bounds(), 0..0);
+    }
+}
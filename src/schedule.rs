@@ -0,0 +1,109 @@
+//! Scheduled writes - writes queued for a future tick
+//!
+//! [`TemporalField::schedule_write`](crate::TemporalField::schedule_write)
+//! parks a write in a min-heap keyed by its target tick; each tick pops
+//! whatever has come due and lands it in the current frame.
+
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Range;
+use ternary_signal::Signal;
+
+/// Handle identifying one scheduled write.
+///
+/// Returned by `TemporalField::schedule_write` and passed to
+/// `cancel_scheduled`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScheduledId(pub(crate) u64);
+
+/// A write waiting for its tick.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScheduledWrite {
+    /// Schedule handle.
+    pub id: ScheduledId,
+    /// Tick at which the write lands.
+    pub at_tick: u64,
+    /// Signals to add.
+    pub signals: Vec<Signal>,
+    /// Dims they are added to.
+    pub range: Range<usize>,
+}
+
+impl ScheduledWrite {
+    /// Landing order: by tick, then in scheduling order.
+    fn key(&self) -> (u64, ScheduledId) {
+        (self.at_tick, self.id)
+    }
+}
+
+/// Heap entry, ordered so the earliest write is on top.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Entry(ScheduledWrite);
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.key().cmp(&self.0.key())
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Pending writes of one field.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Schedule {
+    heap: BinaryHeap<Entry>,
+    next_id: u64,
+}
+
+impl Schedule {
+    /// Allocate the next id.
+    pub(crate) fn next_id(&mut self) -> ScheduledId {
+        let id = ScheduledId(self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    pub(crate) fn push(&mut self, write: ScheduledWrite) {
+        self.heap.push(Entry(write));
+    }
+
+    /// Pop the earliest write if it is due by `tick`.
+    pub(crate) fn pop_due(&mut self, tick: u64) -> Option<ScheduledWrite> {
+        if self.heap.peek()?.0.at_tick > tick {
+            return None;
+        }
+        self.heap.pop().map(|entry| entry.0)
+    }
+
+    /// Remove a pending write. Returns false if it is not pending.
+    pub(crate) fn cancel(&mut self, id: ScheduledId) -> bool {
+        let before = self.heap.len();
+        self.heap.retain(|entry| entry.0.id != id);
+        self.heap.len() < before
+    }
+
+    /// Pending writes in landing order.
+    pub(crate) fn pending(&self) -> Vec<&ScheduledWrite> {
+        let mut pending: Vec<_> = self.heap.iter().map(|entry| &entry.0).collect();
+        pending.sort_by_key(|write| write.key());
+        pending
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.heap.clear();
+    }
+
+    pub(crate) fn heap_bytes(&self) -> usize {
+        self.heap.capacity() * size_of::<Entry>()
+            + self
+                .heap
+                .iter()
+                .map(|entry| entry.0.signals.capacity() * size_of::<Signal>())
+                .sum::<usize>()
+    }
+}
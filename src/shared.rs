@@ -0,0 +1,410 @@
+//! Shared field - concurrent writers, exclusive ticks
+//!
+//! A [`TemporalField`] takes `&mut` for every write, so threads sharing one
+//! usually wrap it in a mutex and serialize on it. A [`SharedField`] hands
+//! out [`SharedWriter`]s instead: each owns a range of dims no other live
+//! writer overlaps, and queues its writes on its own lock. Writers never
+//! wait on each other or on the field.
+//!
+//! ```rust
+//! use temporal_field::{FieldConfig, SharedField, Signal, TemporalField};
+//! use std::thread;
+//!
+//! let shared = SharedField::new(TemporalField::new(FieldConfig::new(96, 10, 255)));
+//! let writers: Vec<_> = [0..32, 32..64, 64..96]
+//!     .into_iter()
+//!     .map(|range| shared.writer(range).unwrap())
+//!     .collect();
+//!
+//! thread::scope(|s| {
+//!     for writer in &writers {
+//!         s.spawn(move || {
+//!             for _ in 0..100 {
+//!                 writer.write(&[Signal::positive_amplified(1, 1); 32]);
+//!             }
+//!         });
+//!     }
+//! });
+//!
+//! shared.tick();
+//! shared.with(|field| assert!(field.read_current().iter().all(|s| s.current() == 100)));
+//! ```
+
+use crate::commands::{FieldCommand, FieldCommands};
+use crate::field::{check_range_within, FieldError, TemporalField};
+use crate::sync::Mutex;
+#[cfg(feature = "std")]
+use crate::ticker::TickerHandle;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{DerefMut, Range};
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use ternary_signal::Signal;
+
+/// A writer's queue as the field sees it.
+struct Slot {
+    range: Range<usize>,
+    queue: FieldCommands,
+    /// Cleared when the writer is dropped; its range is then free again.
+    open: Arc<AtomicBool>,
+}
+
+struct Inner {
+    field: Mutex<TemporalField>,
+    /// The field's dims, so handing out a writer never waits on the field.
+    /// Refreshed after [`SharedField::with`], which may replace the field.
+    dims: AtomicUsize,
+    /// Every writer with queued writes or still alive, in creation order.
+    slots: Mutex<Vec<Slot>>,
+}
+
+/// Thread-safe handle to a field with concurrent writers.
+///
+/// Queued writes land in the field at the next [`flush`](Self::flush),
+/// [`tick`](Self::tick) or [`with`](Self::with), which take the field
+/// exclusively, apply every writer's queue in one batch and then evaluate
+/// thresholds once. Events are fired on that thread, at those points only.
+///
+/// Clones share the same field.
+#[derive(Clone)]
+pub struct SharedField {
+    inner: Arc<Inner>,
+}
+
+impl SharedField {
+    /// Share `field`.
+    pub fn new(field: TemporalField) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                dims: AtomicUsize::new(field.dims()),
+                field: Mutex::new(field),
+                slots: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Get a writer owning `range`.
+    ///
+    /// Fails if the range is empty or past the field's dims, or with
+    /// [`FieldError::WriterOverlap`] if a live writer holds any of it.
+    /// Dropping a writer frees its range; writes it queued still land.
+    pub fn writer(&self, range: Range<usize>) -> Result<SharedWriter, FieldError> {
+        let dims = self.inner.dims.load(Ordering::Acquire);
+        check_range_within("writer", &range, dims)?;
+        let mut slots = self.inner.slots.lock();
+        let held = slots.iter().find(|slot| {
+            slot.open.load(Ordering::Acquire)
+                && slot.range.start < range.end
+                && range.start < slot.range.end
+        });
+        if let Some(slot) = held {
+            return Err(FieldError::WriterOverlap {
+                range,
+                held: slot.range.clone(),
+            });
+        }
+        let queue = FieldCommands::default();
+        let open = Arc::new(AtomicBool::new(true));
+        slots.push(Slot {
+            range: range.clone(),
+            queue: queue.clone(),
+            open: open.clone(),
+        });
+        Ok(SharedWriter { range, queue, open })
+    }
+
+    /// Apply every queued write, then check thresholds and fire events
+    /// once. Returns the number of writes applied.
+    pub fn flush(&self) -> usize {
+        self.flushed().1
+    }
+
+    /// Flush, then advance the field by one tick.
+    pub fn tick(&self) {
+        self.flushed().0.tick();
+    }
+
+    /// Flush, then run `f` on the field with exclusive access - for reads,
+    /// configuration, or anything else on the full field API.
+    pub fn with<R>(&self, f: impl FnOnce(&mut TemporalField) -> R) -> R {
+        let mut field = self.flushed().0;
+        let result = f(&mut field);
+        self.inner.dims.store(field.dims(), Ordering::Release);
+        result
+    }
+
+    /// Lock the field and apply the queued writes, returning the lock and
+    /// the number of writes applied.
+    fn flushed(&self) -> (impl DerefMut<Target = TemporalField> + '_, usize) {
+        let mut field = self.inner.field.lock();
+        let applied = self.apply_queued(&mut field);
+        (field, applied)
+    }
+
+    /// Drain every writer's queue into `field` as one batch, dropping the
+    /// slots of writers that are gone.
+    fn apply_queued(&self, field: &mut TemporalField) -> usize {
+        let mut slots = self.inner.slots.lock();
+        let mut queued = Vec::new();
+        slots.retain(|slot| {
+            // Read before draining: a writer dropped by now queued nothing after
+            let open = slot.open.load(Ordering::Acquire);
+            queued.extend(slot.queue.take());
+            open
+        });
+        drop(slots);
+        if queued.is_empty() {
+            return 0;
+        }
+        let applied = queued.len();
+        field.batch(|field| {
+            for command in queued {
+                match command {
+                    FieldCommand::WriteRegion { signals, range } => {
+                        field.write_region(&signals, range)
+                    }
+                    FieldCommand::SetRegion { signals, range } => field.set_region(&signals, range),
+                }
+            }
+        });
+        applied
+    }
+
+    /// Get the number of live writers.
+    pub fn writer_count(&self) -> usize {
+        let slots = self.inner.slots.lock();
+        slots
+            .iter()
+            .filter(|slot| slot.open.load(Ordering::Acquire))
+            .count()
+    }
+
+    /// Tick on a background thread at the config's `tick_rate_hz`, firing
+    /// observers from that thread; see [`TickerHandle`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tick_rate_hz` is 0.
+    #[cfg(feature = "std")]
+    pub fn spawn_ticker(&self) -> TickerHandle {
+        TickerHandle::spawn(self.clone())
+    }
+
+    /// Take the field back, applying any queued writes. Returns the handle
+    /// unchanged if other clones of it are still alive.
+    pub fn try_unwrap(self) -> Result<TemporalField, Self> {
+        self.flush();
+        match Arc::try_unwrap(self.inner) {
+            Ok(inner) => Ok(inner.field.into_inner()),
+            Err(inner) => Err(Self { inner }),
+        }
+    }
+}
+
+impl fmt::Debug for SharedField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedField")
+            .field("writers", &self.writer_count())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Handle for writing to one range of a [`SharedField`] from any thread.
+///
+/// Writes are queued and land at the field's next flush or tick. Drop the
+/// writer to release its range.
+#[derive(Debug)]
+pub struct SharedWriter {
+    range: Range<usize>,
+    queue: FieldCommands,
+    open: Arc<AtomicBool>,
+}
+
+impl SharedWriter {
+    /// Get the range this writer owns.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Queue an additive write of `signals` over the writer's range.
+    ///
+    /// Signals beyond the range are ignored, as with
+    /// [`TemporalField::write_region`].
+    pub fn write(&self, signals: &[Signal]) {
+        self.queue.write_region(signals, self.range.clone());
+    }
+
+    /// Queue a replacing write of `signals` over the writer's range.
+    pub fn set(&self, signals: &[Signal]) {
+        self.queue.set_region(signals, self.range.clone());
+    }
+
+    /// Get the number of writes queued and not yet flushed.
+    pub fn pending(&self) -> usize {
+        self.queue.len()
+    }
+}
+
+impl Drop for SharedWriter {
+    fn drop(&mut self) {
+        self.open.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::FieldConfig;
+    use crate::observer::{EventMask, MonitoredRegion, RecordingObserver};
+    use std::thread;
+
+    const WRITERS: usize = 4;
+    const WIDTH: usize = 8;
+
+    /// Retention 255 keeps every written unit, so totals can be counted
+    /// (up to the 255 a write saturates at).
+    fn shared() -> SharedField {
+        SharedField::new(TemporalField::new(FieldConfig::new(
+            WRITERS * WIDTH,
+            4,
+            255,
+        )))
+    }
+
+    #[test]
+    fn test_writer_ranges_are_exclusive() {
+        let shared = shared();
+        let a = shared.writer(0..8).unwrap();
+        assert_eq!(
+            shared.writer(4..12).unwrap_err(),
+            FieldError::WriterOverlap {
+                range: 4..12,
+                held: 0..8
+            }
+        );
+        assert!(matches!(
+            shared.writer(30..40),
+            Err(FieldError::RangeOutOfBounds { .. })
+        ));
+        let b = shared.writer(8..16).unwrap();
+        assert_eq!(shared.writer_count(), 2);
+
+        // Dropping frees the range; its queued write still lands
+        a.write(&[Signal::positive_amplified(5, 1); 8]);
+        drop(a);
+        let c = shared.writer(0..4).unwrap();
+        assert_eq!(shared.writer_count(), 2);
+        c.set(&[Signal::positive_amplified(9, 1); 4]);
+        b.write(&[Signal::positive_amplified(7, 1); 8]);
+        assert_eq!(c.pending(), 1);
+
+        assert_eq!(shared.flush(), 3);
+        assert_eq!(c.pending(), 0);
+        let current = shared.with(|field| field.read_region(0..16));
+        assert_eq!(current[0].current(), 9);
+        assert_eq!(current[4].current(), 5);
+        assert_eq!(current[8].current(), 7);
+    }
+
+    #[test]
+    fn test_writer_does_not_wait_on_the_field() {
+        let shared = shared();
+        // The field is locked for the whole closure
+        let writer = shared.with(|_| shared.writer(16..24)).unwrap();
+        writer.write(&[Signal::positive_amplified(3, 1); 8]);
+        assert_eq!(shared.flush(), 1);
+
+        // Replacing the field through `with` moves the bounds along
+        shared.with(|field| *field = TemporalField::new(FieldConfig::new(64, 4, 255)));
+        assert!(shared.writer(40..48).is_ok());
+        assert!(matches!(
+            shared.writer(60..70),
+            Err(FieldError::RangeOutOfBounds { dims: 64, .. })
+        ));
+    }
+
+    #[test]
+    fn test_events_fire_at_flush() {
+        let shared = shared();
+        let recorder = Arc::new(RecordingObserver::new());
+        shared.with(|field| {
+            field
+                .monitor_region(MonitoredRegion::new("a", 0..WIDTH, 10_000))
+                .unwrap();
+            field.subscribe(recorder.clone());
+        });
+
+        let writer = shared.writer(0..WIDTH).unwrap();
+        for _ in 0..4 {
+            writer.write(&[Signal::positive_amplified(30, 1); WIDTH]);
+        }
+        assert!(recorder.is_empty());
+
+        // Four writes, one threshold check
+        shared.tick();
+        assert_eq!(recorder.count_of(&EventMask::REGION_ACTIVE), 1);
+        assert_eq!(recorder.events()[0].0, 0);
+    }
+
+    #[test]
+    fn test_concurrent_writers_lose_nothing() {
+        const ROUNDS: usize = 2000;
+        let shared = shared();
+        let writers: Vec<_> = (0..WRITERS)
+            .map(|i| shared.writer(i * WIDTH..(i + 1) * WIDTH).unwrap())
+            .collect();
+
+        thread::scope(|s| {
+            for writer in &writers {
+                s.spawn(move || {
+                    // One unit per round, rotating over the writer's dims
+                    for round in 0..ROUNDS {
+                        let mut signals = [Signal::ZERO; WIDTH];
+                        signals[round % WIDTH] = Signal::positive_amplified(1, 1);
+                        writer.write(&signals);
+                    }
+                });
+            }
+            let ticker = shared.clone();
+            s.spawn(move || {
+                for _ in 0..200 {
+                    ticker.tick();
+                    ticker.with(|field| field.total_activity());
+                }
+            });
+        });
+
+        shared.flush();
+        let current = shared.with(|field| field.read_current().to_vec());
+        for (dim, signal) in current.iter().enumerate() {
+            assert_eq!(signal.current() as usize, ROUNDS / WIDTH, "dim {}", dim);
+        }
+    }
+
+    #[test]
+    fn test_writer_churn_does_not_deadlock() {
+        let shared = shared();
+        let total = thread::scope(|s| {
+            for i in 0..WRITERS {
+                let shared = shared.clone();
+                s.spawn(move || {
+                    for _ in 0..200 {
+                        let writer = shared.writer(i * WIDTH..(i + 1) * WIDTH).unwrap();
+                        writer.write(&[Signal::positive_amplified(1, 1); WIDTH]);
+                    }
+                });
+            }
+            let ticker = shared.clone();
+            s.spawn(move || (0..200).map(|_| ticker.flush()).sum::<usize>())
+                .join()
+                .unwrap()
+        });
+
+        let total = total + shared.flush();
+        assert_eq!(total, WRITERS * 200);
+        assert_eq!(shared.writer_count(), 0);
+        let field = shared.try_unwrap().unwrap();
+        assert!(field.read_current().iter().all(|s| s.current() == 200));
+    }
+}
@@ -0,0 +1,302 @@
+//! Sparse Field Vector - storage for mostly-empty frames
+//!
+//! ASTRO_004 compliant: the same Signal arithmetic as FieldVector, applied
+//! to the stored signals only.
+
+use crate::config::DecayMode;
+use crate::vector::{FieldVector, FrameMut, FrameRef};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+use ternary_signal::Signal;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Sparse counterpart of [`FieldVector`]: only dims holding something
+/// other than [`Signal::ZERO`] are stored, as ascending indices with their
+/// signals.
+///
+/// Every operation gives the same result as on a `FieldVector` of the same
+/// dims; decay costs O(stored signals) instead of O(dims).
+///
+/// ```rust
+/// use temporal_field::{Signal, SparseFieldVector};
+///
+/// let mut v = SparseFieldVector::new(8192);
+/// v.add_to_range(&[Signal::positive_amplified(100, 1); 4], 10..14);
+/// v.decay(128);
+/// assert_eq!(v.non_zero_count(), 4);
+/// assert_eq!(v.get(10).current(), 50);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SparseFieldVector {
+    dims: usize,
+    /// Stored dims, ascending.
+    indices: Vec<u32>,
+    /// Signal at each of `indices`, never `Signal::ZERO`.
+    signals: Vec<Signal>,
+}
+
+impl SparseFieldVector {
+    /// Create a new zero vector.
+    pub fn new(dims: usize) -> Self {
+        assert!(dims <= u32::MAX as usize, "sparse dims must fit in u32");
+        Self {
+            dims,
+            indices: Vec::new(),
+            signals: Vec::new(),
+        }
+    }
+
+    /// Pack a dense frame.
+    pub fn from_dense<'a>(frame: impl Into<FrameRef<'a>>) -> Self {
+        let frame = frame.into();
+        let mut sparse = Self::new(frame.dims());
+        sparse.pack(frame.as_slice());
+        sparse
+    }
+
+    /// Unpack into a dense vector.
+    pub fn to_vector(&self) -> FieldVector {
+        let mut signals = vec![Signal::ZERO; self.dims];
+        self.unpack_into(&mut signals);
+        FieldVector::from_signals(signals)
+    }
+
+    /// Replace the content with the non-ZERO signals of `signals`, reusing
+    /// the allocation.
+    pub(crate) fn pack(&mut self, signals: &[Signal]) {
+        self.clear();
+        for (i, &s) in signals.iter().enumerate() {
+            if s != Signal::ZERO {
+                self.indices.push(i as u32);
+                self.signals.push(s);
+            }
+        }
+    }
+
+    /// Write the content over `out` (`dims` long), zeroing everything else.
+    pub(crate) fn unpack_into(&self, out: &mut [Signal]) {
+        out.fill(Signal::ZERO);
+        for (&i, &s) in self.indices.iter().zip(&self.signals) {
+            out[i as usize] = s;
+        }
+    }
+
+    /// Run a kernel over the stored signals, then drop those it zeroed.
+    ///
+    /// Only for kernels that leave `Signal::ZERO` as it is (decay, scale,
+    /// clear): dims that are not stored never see the kernel.
+    pub(crate) fn update_stored(&mut self, f: impl FnOnce(FrameMut<'_>)) {
+        f(FrameMut::new(&mut self.signals));
+        let mut kept = 0;
+        for j in 0..self.signals.len() {
+            if self.signals[j] != Signal::ZERO {
+                self.indices[kept] = self.indices[j];
+                self.signals[kept] = self.signals[j];
+                kept += 1;
+            }
+        }
+        self.indices.truncate(kept);
+        self.signals.truncate(kept);
+    }
+
+    /// Heap bytes held by the stored indices and signals.
+    pub(crate) fn heap_bytes(&self) -> usize {
+        self.indices.capacity() * size_of::<u32>() + self.signals.capacity() * size_of::<Signal>()
+    }
+
+    /// Get dimensions.
+    #[inline]
+    pub fn dims(&self) -> usize {
+        self.dims
+    }
+
+    /// Get Signal at index (`Signal::ZERO` if not stored).
+    pub fn get(&self, idx: usize) -> Signal {
+        match self.indices.binary_search(&(idx as u32)) {
+            Ok(j) => self.signals[j],
+            Err(_) => Signal::ZERO,
+        }
+    }
+
+    /// Iterate `(dim, signal)` over the stored signals, ascending.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, Signal)> + '_ {
+        self.indices
+            .iter()
+            .zip(&self.signals)
+            .map(|(&i, &s)| (i as usize, s))
+    }
+
+    /// Position range of the stored dims inside `range`.
+    fn stored_in(&self, range: &Range<usize>) -> Range<usize> {
+        let lo = self
+            .indices
+            .partition_point(|&i| (i as usize) < range.start);
+        let hi = self.indices.partition_point(|&i| (i as usize) < range.end);
+        lo..hi.max(lo)
+    }
+
+    /// Rewrite the dims of `span` (clamped to the vector): dim `i` becomes
+    /// `f(i, old signal)`.
+    fn rewrite(&mut self, span: Range<usize>, mut f: impl FnMut(usize, Signal) -> Signal) {
+        let span = span.start..span.end.min(self.dims);
+        if span.is_empty() {
+            return;
+        }
+        let stored = self.stored_in(&span);
+        let mut old = stored.start;
+        let mut indices = Vec::new();
+        let mut signals = Vec::new();
+        for i in span {
+            let prev = match self.indices.get(old) {
+                Some(&at) if old < stored.end && at as usize == i => {
+                    old += 1;
+                    self.signals[old - 1]
+                }
+                _ => Signal::ZERO,
+            };
+            let s = f(i, prev);
+            if s != Signal::ZERO {
+                indices.push(i as u32);
+                signals.push(s);
+            }
+        }
+        self.indices.splice(stored.clone(), indices);
+        self.signals.splice(stored, signals);
+    }
+
+    /// Add Signals to a range (saturating at ±65,025), like
+    /// [`FieldVector::add_to_range`].
+    pub fn add_to_range(&mut self, signals: &[Signal], range: Range<usize>) {
+        let end = range.end.min(range.start.saturating_add(signals.len()));
+        let start = range.start;
+        self.rewrite(start..end, |i, s| {
+            let sum = s.current() as i64 + signals[i - start].current() as i64;
+            Signal::from_current(sum.clamp(-65025, 65025) as i32)
+        });
+    }
+
+    /// Set Signals in a range (replace), like [`FieldVector::set_range`].
+    pub fn set_range(&mut self, signals: &[Signal], range: Range<usize>) {
+        let end = range.end.min(range.start.saturating_add(signals.len()));
+        let start = range.start;
+        self.rewrite(start..end, |i, _| signals[i - start]);
+    }
+
+    /// Get Signals for a range (clamped to the vector).
+    pub fn get_range(&self, range: Range<usize>) -> Vec<Signal> {
+        let end = range.end.min(self.dims);
+        let start = range.start.min(end);
+        let mut out = vec![Signal::ZERO; end - start];
+        let stored = self.stored_in(&(start..end));
+        for j in stored {
+            out[self.indices[j] as usize - start] = self.signals[j];
+        }
+        out
+    }
+
+    /// Σ(effective magnitude²) over a range.
+    pub fn range_energy(&self, range: Range<usize>) -> u64 {
+        self.signals[self.stored_in(&range)]
+            .iter()
+            .map(|s| {
+                let eff = s.effective_magnitude() as u64;
+                eff * eff
+            })
+            .sum()
+    }
+
+    /// Decay all values by retention factor, like [`FieldVector::decay`].
+    pub fn decay(&mut self, retention: u8) {
+        self.update_stored(|mut frame| frame.decay(retention));
+    }
+
+    /// Decay all values under a [`DecayMode`], like
+    /// [`FieldVector::decay_with`].
+    pub fn decay_with(&mut self, mode: DecayMode) {
+        self.update_stored(|mut frame| frame.decay_with(mode));
+    }
+
+    /// Count non-zero elements, like [`FieldVector::non_zero_count`].
+    pub fn non_zero_count(&self) -> usize {
+        self.signals.iter().filter(|s| s.magnitude > 0).count()
+    }
+
+    /// Check if all values are zero, like [`FieldVector::is_zero`].
+    pub fn is_zero(&self) -> bool {
+        self.signals.iter().all(|s| s.magnitude == 0)
+    }
+
+    /// Zero every dim.
+    pub fn clear(&mut self) {
+        self.indices.clear();
+        self.signals.clear();
+    }
+}
+
+impl From<&FieldVector> for SparseFieldVector {
+    fn from(vector: &FieldVector) -> Self {
+        Self::from_dense(vector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Same writes on a dense and a sparse vector.
+    fn pair() -> (FieldVector, SparseFieldVector) {
+        let mut dense = FieldVector::new(64);
+        let mut sparse = SparseFieldVector::new(64);
+        let writes = [
+            (vec![Signal::positive_amplified(200, 3); 8], 4..12),
+            (vec![Signal::negative_amplified(50, 1); 6], 10..16),
+            (vec![Signal::positive_amplified(255, 255); 2], 62..70),
+        ];
+        for (signals, range) in writes {
+            dense.add_to_range(&signals, range.clone());
+            sparse.add_to_range(&signals, range);
+        }
+        (dense, sparse)
+    }
+
+    #[test]
+    fn test_sparse_matches_dense() {
+        let (mut dense, mut sparse) = pair();
+        assert_eq!(sparse.to_vector().as_slice(), dense.as_slice());
+        assert_eq!(sparse.non_zero_count(), dense.non_zero_count());
+        assert_eq!(sparse.range_energy(0..64), dense.range_energy(0..64));
+        assert_eq!(sparse.get_range(8..20), dense.get_range(8..20));
+
+        let set = [Signal::ZERO, Signal::positive_amplified(7, 1), Signal::ZERO];
+        dense.set_range(&set, 10..13);
+        sparse.set_range(&set, 10..13);
+        assert_eq!(sparse.to_vector().as_slice(), dense.as_slice());
+
+        for mode in [
+            DecayMode::Multiplicative(200),
+            DecayMode::Linear(40),
+            DecayMode::Floor {
+                retention: 230,
+                min_magnitude: 30,
+            },
+        ] {
+            dense.decay_with(mode);
+            sparse.decay_with(mode);
+            assert_eq!(sparse.to_vector().as_slice(), dense.as_slice());
+            assert_eq!(sparse, SparseFieldVector::from(&dense));
+        }
+    }
+
+    #[test]
+    fn test_decay_drops_zeroed_signals() {
+        let (_, mut sparse) = pair();
+        sparse.decay(0);
+        assert!(sparse.is_zero());
+        assert_eq!(sparse.iter().count(), 0);
+        assert_eq!(sparse.get(5), Signal::ZERO);
+    }
+}
@@ -0,0 +1,312 @@
+//! Event stream - field events as a `futures` Stream
+//!
+//! [`TemporalField::event_stream`] subscribes a bounded queue and returns
+//! the [`EventStream`] reading it. The field pushes into the queue without
+//! waiting; a consumer that falls behind loses the oldest events, counted
+//! by [`EventStream::lagged`], like a lagging `tokio::sync::broadcast`
+//! receiver.
+//!
+//! Requires the `futures` feature.
+
+use crate::field::TemporalField;
+use crate::observer::{FieldEvent, FieldObserver, SubscriptionGuard, SubscriptionId};
+use crate::sync::Mutex;
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use futures_core::Stream;
+
+/// Queue shared between the stream and its observer.
+struct Queue {
+    events: VecDeque<FieldEvent>,
+    capacity: usize,
+    /// Events dropped to make room, in total.
+    lagged: u64,
+    /// Only queue events involving one of these regions (empty = all).
+    regions: Vec<String>,
+    waker: Option<Waker>,
+    /// Set when the field lets go of the observer.
+    closed: bool,
+}
+
+/// The subscribed half: pushes events into the queue.
+struct StreamObserver {
+    queue: Arc<Mutex<Queue>>,
+}
+
+impl FieldObserver for StreamObserver {
+    fn on_event(&self, event: &FieldEvent) {
+        let waker = {
+            let mut queue = self.queue.lock();
+            if !queue.selects(event) {
+                return;
+            }
+            if queue.events.len() >= queue.capacity {
+                queue.events.pop_front();
+                queue.lagged += 1;
+            }
+            queue.events.push_back(event.clone());
+            queue.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl Drop for StreamObserver {
+    fn drop(&mut self) {
+        let waker = {
+            let mut queue = self.queue.lock();
+            queue.closed = true;
+            queue.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl Queue {
+    fn selects(&self, event: &FieldEvent) -> bool {
+        self.regions.is_empty()
+            || self
+                .regions
+                .iter()
+                .any(|name| TemporalField::event_involves(event, name))
+    }
+}
+
+/// Stream of a field's events, from [`TemporalField::event_stream`].
+///
+/// Holds up to `buffer` undelivered events. When the field fires into a
+/// full buffer, the oldest event is dropped and [`lagged`](Self::lagged)
+/// goes up by one: a slow consumer sees the most recent events, with gaps.
+/// The stream ends once the field drops the subscription (field dropped,
+/// or [`TemporalField::unsubscribe`] with [`id`](Self::id)) and the
+/// buffer is drained. Dropping the stream unsubscribes it.
+///
+/// ```rust
+/// use temporal_field::{FieldConfig, FieldEvent, MonitoredRegion, Signal, TemporalField};
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut field = TemporalField::new(FieldConfig::new(64, 10, 128));
+/// field.monitor_region(MonitoredRegion::new("a", 0..32, 50_000)).unwrap();
+/// field.monitor_region(MonitoredRegion::new("b", 32..64, 50_000)).unwrap();
+/// let mut events = field.event_stream(16).filter_regions(["b"]);
+///
+/// field.write_region(&[Signal::positive_amplified(128, 1); 64], 0..64);
+/// assert!(matches!(
+///     events.recv().await,
+///     Some(FieldEvent::RegionActive { name, .. }) if name == "b"
+/// ));
+/// assert!(matches!(events.recv().await, Some(FieldEvent::Convergence { .. })));
+///
+/// drop(field);
+/// assert!(events.recv().await.is_none());
+/// # });
+/// ```
+pub struct EventStream {
+    queue: Arc<Mutex<Queue>>,
+    guard: SubscriptionGuard,
+}
+
+impl EventStream {
+    pub(crate) fn subscribe(field: &mut TemporalField, buffer: usize) -> Self {
+        assert!(buffer > 0, "buffer must be > 0");
+        let queue = Arc::new(Mutex::new(Queue {
+            events: VecDeque::with_capacity(buffer),
+            capacity: buffer,
+            lagged: 0,
+            regions: Vec::new(),
+            waker: None,
+            closed: false,
+        }));
+        let observer = StreamObserver {
+            queue: queue.clone(),
+        };
+        let guard = field.subscribe_guarded(Arc::new(observer));
+        Self { queue, guard }
+    }
+
+    /// Only yield events involving one of the named regions.
+    ///
+    /// Filtered events never take buffer space. Field-wide events
+    /// (Tick, Global*, FrameAdvanced, ...) pass any region filter,
+    /// Convergence passes if a named region is among the active ones, and
+    /// ConvergenceEnded if one is among the converged ones.
+    /// Events already buffered are filtered too.
+    pub fn filter_regions<S: Into<String>>(self, names: impl IntoIterator<Item = S>) -> Self {
+        {
+            let mut queue = self.queue.lock();
+            queue.regions.extend(names.into_iter().map(Into::into));
+            let Queue {
+                events, regions, ..
+            } = &mut *queue;
+            events.retain(|event| {
+                regions
+                    .iter()
+                    .any(|name| TemporalField::event_involves(event, name))
+            });
+        }
+        self
+    }
+
+    /// Get the next event, waiting for one if the buffer is empty.
+    ///
+    /// Returns None once the stream has ended.
+    pub async fn recv(&mut self) -> Option<FieldEvent> {
+        core::future::poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await
+    }
+
+    /// Get the number of events dropped because the buffer was full.
+    pub fn lagged(&self) -> u64 {
+        self.queue.lock().lagged
+    }
+
+    /// Get the number of buffered events.
+    pub fn len(&self) -> usize {
+        self.queue.lock().events.len()
+    }
+
+    /// Check if no events are buffered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the id of the stream's subscription.
+    pub fn id(&self) -> SubscriptionId {
+        self.guard.id()
+    }
+}
+
+impl Stream for EventStream {
+    type Item = FieldEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<FieldEvent>> {
+        let mut queue = self.queue.lock();
+        if let Some(event) = queue.events.pop_front() {
+            return Poll::Ready(Some(event));
+        }
+        if queue.closed {
+            return Poll::Ready(None);
+        }
+        match &mut queue.waker {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            waker => *waker = Some(cx.waker().clone()),
+        }
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), None)
+    }
+}
+
+impl fmt::Debug for EventStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let queue = self.queue.lock();
+        f.debug_struct("EventStream")
+            .field("id", &self.guard.id())
+            .field("buffered", &queue.events.len())
+            .field("lagged", &queue.lagged)
+            .field("regions", &queue.regions)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::FieldConfig;
+    use crate::observer::MonitoredRegion;
+    use std::time::Duration;
+    use ternary_signal::Signal;
+
+    fn ticking() -> TemporalField {
+        let mut field = TemporalField::new(FieldConfig::new(8, 4, 230));
+        field.set_emit_tick_events(true);
+        field
+    }
+
+    #[tokio::test]
+    async fn test_full_buffer_drops_oldest() {
+        let mut field = ticking();
+        let mut events = field.event_stream(3);
+        field.tick_n(10);
+        assert_eq!(events.len(), 3);
+        assert_eq!(events.lagged(), 7);
+
+        let mut ticks = Vec::new();
+        while let Some(event) = events.recv().await {
+            ticks.push(event.tick());
+            if events.is_empty() {
+                break;
+            }
+        }
+        assert_eq!(ticks, [8, 9, 10]);
+    }
+
+    #[tokio::test]
+    async fn test_filter_and_unsubscribe_on_drop() {
+        let mut field = TemporalField::new(FieldConfig::new(64, 10, 128));
+        field
+            .monitor_region(MonitoredRegion::new("a", 0..32, 50_000))
+            .unwrap();
+        field
+            .monitor_region(MonitoredRegion::new("b", 32..64, 50_000))
+            .unwrap();
+        let mut events = field.event_stream(8).filter_regions(["a"]);
+
+        // RegionActive for "b" is filtered out; Convergence involves "a"
+        field.write_region(&[Signal::positive_amplified(128, 1); 64], 0..64);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events.recv().await,
+            Some(FieldEvent::RegionActive { name, .. }) if name == "a"
+        ));
+        assert!(matches!(
+            events.recv().await,
+            Some(FieldEvent::Convergence { .. })
+        ));
+
+        assert_eq!(field.observer_count(), 1);
+        drop(events);
+        assert_eq!(field.observer_count(), 0);
+
+        // Unsubscribing from the field ends the stream
+        let mut events = field.event_stream(8);
+        assert!(field.unsubscribe(events.id()));
+        assert!(events.recv().await.is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_slow_consumer_lags() {
+        const TICKS: u64 = 200;
+        let mut field = ticking();
+        let mut events = field.event_stream(8);
+        let producer = std::thread::spawn(move || {
+            for _ in 0..TICKS {
+                field.tick();
+                std::thread::sleep(Duration::from_micros(100));
+            }
+        });
+
+        let mut received = Vec::new();
+        while let Some(event) = events.recv().await {
+            received.push(event.tick());
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        producer.join().unwrap();
+
+        // Every tick was either delivered, in order, or counted as lagged
+        assert!(received.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(received.last(), Some(&TICKS));
+        assert_eq!(received.len() as u64 + events.lagged(), TICKS);
+        assert!(events.lagged() > 0);
+    }
+}
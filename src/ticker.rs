@@ -0,0 +1,242 @@
+//! Ticker - drive a field in real time
+//!
+//! A background thread that ticks a [`SharedField`] at the config's
+//! `tick_rate_hz`. Deadlines are absolute (tick `n` is due `n` periods
+//! after the ticker started or resumed), so sleep jitter never accumulates
+//! into drift: a late tick is followed by an early one, and a ticker that
+//! falls behind catches up back to back.
+//!
+//! Observers fire on the ticker thread, from inside its ticks - keep them
+//! short, or hand events off to a channel.
+
+use crate::field::TemporalField;
+use crate::shared::SharedField;
+use crate::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+#[derive(Default)]
+struct State {
+    paused: bool,
+    stopped: bool,
+}
+
+#[derive(Default)]
+struct Control {
+    state: Mutex<State>,
+    /// Signalled on pause, resume and stop.
+    changed: Condvar,
+    behind: AtomicU64,
+}
+
+/// Handle to a ticker thread spawned by [`TemporalField::spawn_ticker`] or
+/// [`SharedField::spawn_ticker`].
+///
+/// Dropping the handle stops the ticker and waits for its thread.
+///
+/// ```rust
+/// use temporal_field::{FieldConfig, Signal, TemporalField};
+/// use std::time::Duration;
+///
+/// let mut config = FieldConfig::new(64, 10, 230);
+/// config.tick_rate_hz = 1000;
+/// let ticker = TemporalField::new(config).spawn_ticker();
+///
+/// let writer = ticker.shared().writer(0..64).unwrap();
+/// writer.write(&[Signal::positive_amplified(100, 1); 64]);
+/// std::thread::sleep(Duration::from_millis(20));
+/// drop(writer);
+///
+/// let field = ticker.stop().unwrap();
+/// assert!(field.tick_count() > 0);
+/// ```
+pub struct TickerHandle {
+    shared: SharedField,
+    control: Arc<Control>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TickerHandle {
+    /// Start ticking `shared` at its config's `tick_rate_hz`.
+    pub(crate) fn spawn(shared: SharedField) -> Self {
+        let hz = shared.with(|field| field.config().tick_rate_hz);
+        assert!(hz > 0, "ticker needs tick_rate_hz > 0");
+        let control = Arc::new(Control::default());
+        let thread = {
+            let shared = shared.clone();
+            let control = control.clone();
+            thread::Builder::new()
+                .name("temporal-field-ticker".into())
+                .spawn(move || run(&shared, &control, hz))
+                .expect("failed to spawn ticker thread")
+        };
+        Self {
+            shared,
+            control,
+            thread: Some(thread),
+        }
+    }
+
+    /// Get the field being ticked, for writers and reads.
+    pub fn shared(&self) -> &SharedField {
+        &self.shared
+    }
+
+    /// Stop ticking until [`resume`](Self::resume). A tick already underway
+    /// still finishes.
+    pub fn pause(&self) {
+        self.set(|state| state.paused = true);
+    }
+
+    /// Resume ticking. Time spent paused is not caught up: the next tick is
+    /// due one period from now.
+    pub fn resume(&self) {
+        self.set(|state| state.paused = false);
+    }
+
+    /// Check if the ticker is paused.
+    pub fn is_paused(&self) -> bool {
+        self.control.state.lock().paused
+    }
+
+    /// Get how many ticks were overdue after the ticker's latest tick.
+    ///
+    /// Zero while the ticker keeps up; it catches up on a backlog by
+    /// ticking back to back.
+    pub fn ticks_behind(&self) -> u64 {
+        self.control.behind.load(Ordering::Relaxed)
+    }
+
+    /// Stop the ticker and take the field back.
+    ///
+    /// Returns the [`SharedField`] instead if other clones of it are still
+    /// alive (see [`SharedField::try_unwrap`]).
+    pub fn stop(self) -> Result<TemporalField, SharedField> {
+        let shared = self.shared.clone();
+        drop(self);
+        shared.try_unwrap()
+    }
+
+    fn set(&self, f: impl FnOnce(&mut State)) {
+        f(&mut self.control.state.lock());
+        self.control.changed.notify_all();
+    }
+
+    fn halt(&mut self) {
+        self.set(|state| state.stopped = true);
+        if let Some(thread) = self.thread.take() {
+            // Observer panics are caught inside the tick; nothing else panics
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for TickerHandle {
+    fn drop(&mut self) {
+        self.halt();
+    }
+}
+
+impl core::fmt::Debug for TickerHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TickerHandle")
+            .field("paused", &self.is_paused())
+            .field("ticks_behind", &self.ticks_behind())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Time from an epoch to tick `n` at `hz`.
+fn span(n: u64, hz: u32) -> Duration {
+    Duration::from_nanos((n as u128 * 1_000_000_000 / hz as u128) as u64)
+}
+
+/// The ticker thread: tick at every deadline until stopped.
+fn run(shared: &SharedField, control: &Control, hz: u32) {
+    let mut epoch = Instant::now();
+    let mut ticks = 0u64;
+    let mut state = control.state.lock();
+    loop {
+        if state.stopped {
+            return;
+        }
+        if state.paused {
+            state = control
+                .changed
+                .wait_while(state, |state| state.paused && !state.stopped)
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            epoch = Instant::now();
+            ticks = 0;
+            continue;
+        }
+        let deadline = epoch + span(ticks + 1, hz);
+        let now = Instant::now();
+        if now < deadline {
+            state = control
+                .changed
+                .wait_timeout(state, deadline - now)
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .0;
+            continue;
+        }
+
+        drop(state);
+        shared.tick();
+        ticks += 1;
+        let due = (now - epoch).as_nanos() * hz as u128 / 1_000_000_000;
+        let behind = (due as u64).saturating_sub(ticks);
+        control.behind.store(behind, Ordering::Relaxed);
+        state = control.state.lock();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::FieldConfig;
+
+    fn field(hz: u32) -> TemporalField {
+        let mut config = FieldConfig::new(16, 4, 230);
+        config.tick_rate_hz = hz;
+        TemporalField::new(config)
+    }
+
+    #[test]
+    fn test_ticker_keeps_rate() {
+        let ticker = field(100).spawn_ticker();
+        thread::sleep(Duration::from_millis(100));
+        ticker.pause();
+        let field = ticker.stop().unwrap();
+        assert!(
+            (9..=11).contains(&field.tick_count()),
+            "{} ticks",
+            field.tick_count()
+        );
+    }
+
+    #[test]
+    fn test_ticker_pause_resume() {
+        let ticker = field(1000).spawn_ticker();
+        thread::sleep(Duration::from_millis(20));
+        ticker.pause();
+        assert!(ticker.is_paused());
+        // A tick already underway still finishes
+        thread::sleep(Duration::from_millis(5));
+        let paused_at = ticker.shared().with(|field| field.tick_count());
+        assert!(paused_at > 0);
+
+        thread::sleep(Duration::from_millis(30));
+        assert_eq!(ticker.shared().with(|field| field.tick_count()), paused_at);
+
+        ticker.resume();
+        thread::sleep(Duration::from_millis(20));
+        assert!(ticker.shared().with(|field| field.tick_count()) > paused_at);
+
+        // Another clone keeps the field shared
+        let other = ticker.shared().clone();
+        assert!(ticker.stop().is_err());
+        assert!(other.try_unwrap().is_ok());
+    }
+}
@@ -0,0 +1,471 @@
+//! Tokio observers - bridge field events into async code
+//!
+//! The field fires events synchronously from inside `write_region` and
+//! `tick`. Async consumers must not slow that path down, so every observer
+//! here hands events off without awaiting and returns immediately.
+//! A full queue drops events according to a [`DropPolicy`].
+//!
+//! - [`AsyncObserverAdapter`]: runs an [`AsyncFieldObserver`] on a task
+//! - [`TokioChannelObserver`]: forwards into a bounded `tokio::sync::mpsc`
+//! - [`WatchObserver`]: latest region state per region via `tokio::sync::watch`
+//!
+//! Requires the `tokio` feature.
+
+use crate::observer::{DropPolicy, FieldEvent, FieldObserver};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{mpsc, watch, Notify};
+
+/// Observer whose event handler can await.
+///
+/// Implement with a plain `async fn`:
+///
+/// ```rust
+/// use temporal_field::{AsyncFieldObserver, FieldEvent};
+///
+/// struct Poster;
+///
+/// impl AsyncFieldObserver for Poster {
+///     async fn on_event(&self, event: FieldEvent) {
+///         // await an HTTP post, an async channel send, ...
+///         let _ = event;
+///     }
+/// }
+/// ```
+///
+/// Subscribe it through an [`AsyncObserverAdapter`].
+pub trait AsyncFieldObserver: Send + Sync + 'static {
+    /// Called when a field event occurs
+    fn on_event(&self, event: FieldEvent) -> impl Future<Output = ()> + Send;
+}
+
+/// State shared between the adapter and its delivery task.
+struct AsyncQueue {
+    events: Mutex<VecDeque<FieldEvent>>,
+    notify: Notify,
+    closed: AtomicBool,
+    dropped: AtomicU64,
+}
+
+/// Bridges an [`AsyncFieldObserver`] into the sync `subscribe` path.
+///
+/// `on_event` pushes into a bounded queue and returns without blocking.
+/// A task spawned on the current tokio runtime drains the queue and awaits
+/// the async observer one event at a time, in order. Dropping the adapter
+/// (i.e. unsubscribing it) stops the task once the queue is drained.
+pub struct AsyncObserverAdapter {
+    queue: Arc<AsyncQueue>,
+    capacity: usize,
+    policy: DropPolicy,
+}
+
+impl AsyncObserverAdapter {
+    /// Spawn the delivery task for `observer` and return the adapter.
+    ///
+    /// # Panics
+    /// Panics if called outside a tokio runtime, or if capacity is 0.
+    pub fn spawn<O: AsyncFieldObserver>(observer: O, capacity: usize, policy: DropPolicy) -> Self {
+        assert!(capacity > 0, "capacity must be > 0");
+        let queue = Arc::new(AsyncQueue {
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+            notify: Notify::new(),
+            closed: AtomicBool::new(false),
+            dropped: AtomicU64::new(0),
+        });
+
+        let task_queue = queue.clone();
+        tokio::spawn(async move {
+            loop {
+                let next = task_queue.events.lock().unwrap().pop_front();
+                match next {
+                    Some(event) => observer.on_event(event).await,
+                    None if task_queue.closed.load(Ordering::Acquire) => break,
+                    None => task_queue.notify.notified().await,
+                }
+            }
+        });
+
+        Self {
+            queue,
+            capacity,
+            policy,
+        }
+    }
+
+    /// Get the number of events dropped because the queue was full.
+    pub fn dropped(&self) -> u64 {
+        self.queue.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Get the number of events waiting for delivery.
+    pub fn pending(&self) -> usize {
+        self.queue.events.lock().unwrap().len()
+    }
+}
+
+impl FieldObserver for AsyncObserverAdapter {
+    fn on_event(&self, event: &FieldEvent) {
+        {
+            let mut events = self.queue.events.lock().unwrap();
+            if events.len() >= self.capacity {
+                self.queue.dropped.fetch_add(1, Ordering::Relaxed);
+                match self.policy {
+                    DropPolicy::DropOldest => {
+                        events.pop_front();
+                    }
+                    DropPolicy::DropNewest => return,
+                }
+            }
+            events.push_back(event.clone());
+        }
+        self.queue.notify.notify_one();
+    }
+}
+
+impl Drop for AsyncObserverAdapter {
+    fn drop(&mut self) {
+        self.queue.closed.store(true, Ordering::Release);
+        self.queue.notify.notify_one();
+    }
+}
+
+/// Channel-based observer for a bounded `tokio::sync::mpsc` channel.
+///
+/// Uses `try_send`, so a full channel never blocks the field. A sender cannot
+/// evict events already queued in the channel, so under `DropOldest` the
+/// overflow is held in a local backlog of the same capacity (oldest evicted
+/// first). While the backlog is non-empty a task on the current tokio
+/// runtime waits for room in the channel and flushes it, in order, without
+/// needing another event. Outside a runtime the backlog is flushed by the
+/// next event instead.
+pub struct TokioChannelObserver {
+    sender: mpsc::Sender<FieldEvent>,
+    policy: DropPolicy,
+    backlog: Arc<Backlog>,
+    dropped: AtomicU64,
+}
+
+/// Overflow held back under `DropOldest`.
+#[derive(Default)]
+struct Backlog {
+    events: Mutex<VecDeque<FieldEvent>>,
+    /// Whether a flush task is running; only changed under `events`' lock.
+    flushing: AtomicBool,
+}
+
+impl Backlog {
+    /// Move backlogged events into the channel as room appears, until the
+    /// backlog is empty or the receiver is gone.
+    async fn flush(&self, sender: mpsc::Sender<FieldEvent>) {
+        loop {
+            let permit = sender.reserve().await;
+            let mut events = self.events.lock().unwrap();
+            match (permit, events.pop_front()) {
+                (Ok(permit), Some(event)) => permit.send(event),
+                (permit, _) => {
+                    // Receiver gone, or nothing left to flush. Cleared under
+                    // the lock, so the next backlog spawns a new task.
+                    if permit.is_err() {
+                        events.clear();
+                    }
+                    self.flushing.store(false, Ordering::Relaxed);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+impl TokioChannelObserver {
+    /// Create an observer that drops new events when the channel is full.
+    pub fn new(sender: mpsc::Sender<FieldEvent>) -> Self {
+        Self {
+            sender,
+            policy: DropPolicy::DropNewest,
+            backlog: Arc::default(),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Set the drop policy used when the channel is full.
+    pub fn with_policy(mut self, policy: DropPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Get the number of events dropped because the channel was full.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl FieldObserver for TokioChannelObserver {
+    fn on_event(&self, event: &FieldEvent) {
+        if self.policy == DropPolicy::DropNewest {
+            if let Err(TrySendError::Full(_)) = self.sender.try_send(event.clone()) {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+            return;
+        }
+
+        let mut backlog = self.backlog.events.lock().unwrap();
+        backlog.push_back(event.clone());
+        while let Some(next) = backlog.pop_front() {
+            match self.sender.try_send(next) {
+                Ok(()) => {}
+                Err(TrySendError::Full(next)) => {
+                    backlog.push_front(next);
+                    break;
+                }
+                // Receiver gone: nothing will ever drain the backlog
+                Err(TrySendError::Closed(_)) => {
+                    backlog.clear();
+                    return;
+                }
+            }
+        }
+        while backlog.len() > self.sender.max_capacity() {
+            backlog.pop_front();
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        if backlog.is_empty() || self.backlog.flushing.load(Ordering::Relaxed) {
+            return;
+        }
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            self.backlog.flushing.store(true, Ordering::Relaxed);
+            let task_backlog = self.backlog.clone();
+            let sender = self.sender.clone();
+            runtime.spawn(async move { task_backlog.flush(sender).await });
+        }
+    }
+}
+
+/// Latest region event per region name, as published by [`WatchObserver`].
+pub type RegionStates = HashMap<String, FieldEvent>;
+
+/// Publishes the most recent RegionActive/RegionQuiet event per region.
+///
+/// Built on `tokio::sync::watch`: receivers see only the latest state, never
+/// a backlog, which is what a UI wants. Other event types are ignored.
+pub struct WatchObserver {
+    sender: watch::Sender<RegionStates>,
+}
+
+impl WatchObserver {
+    /// Create the observer and a first receiver.
+    pub fn new() -> (Self, watch::Receiver<RegionStates>) {
+        let (sender, receiver) = watch::channel(RegionStates::new());
+        (Self { sender }, receiver)
+    }
+
+    /// Create another receiver for the same region states.
+    pub fn subscribe(&self) -> watch::Receiver<RegionStates> {
+        self.sender.subscribe()
+    }
+}
+
+impl FieldObserver for WatchObserver {
+    fn on_event(&self, event: &FieldEvent) {
+        let name = match event {
+            FieldEvent::RegionActive { name, .. } | FieldEvent::RegionQuiet { name, .. } => {
+                name.clone()
+            }
+            _ => return,
+        };
+        self.sender.send_modify(|states| {
+            states.insert(name, event.clone());
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FieldConfig, MonitoredRegion, Signal, TemporalField};
+    use tokio::sync::mpsc;
+
+    struct Forward(mpsc::UnboundedSender<FieldEvent>);
+
+    impl AsyncFieldObserver for Forward {
+        async fn on_event(&self, event: FieldEvent) {
+            tokio::task::yield_now().await;
+            let _ = self.0.send(event);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_observer_receives_convergence() {
+        let mut field = TemporalField::new(FieldConfig::new(64, 10, 242));
+        field
+            .monitor_region(MonitoredRegion::new("a", 0..32, 50_000))
+            .unwrap();
+        field
+            .monitor_region(MonitoredRegion::new("b", 32..64, 50_000))
+            .unwrap();
+        field.set_convergence_threshold(2);
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let adapter = AsyncObserverAdapter::spawn(Forward(tx), 16, DropPolicy::DropNewest);
+        field.subscribe(Arc::new(adapter));
+
+        let signals = vec![Signal::positive_amplified(128, 1); 32];
+        field.write_region(&signals, 0..32);
+        field.write_region(&signals, 32..64);
+
+        let converged = tokio::time::timeout(std::time::Duration::from_secs(1), async {
+            while let Some(event) = rx.recv().await {
+                if let FieldEvent::Convergence { active_regions, .. } = event {
+                    return active_regions.len();
+                }
+            }
+            0
+        })
+        .await
+        .expect("convergence should arrive");
+        assert_eq!(converged, 2);
+    }
+
+    #[test]
+    fn test_drop_policy() {
+        // Current-thread runtime that never runs the task: the queue only fills
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let _guard = rt.enter();
+
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let newest = AsyncObserverAdapter::spawn(Forward(tx.clone()), 2, DropPolicy::DropNewest);
+        let oldest = AsyncObserverAdapter::spawn(Forward(tx), 2, DropPolicy::DropOldest);
+
+        for tick in 0..5 {
+            let event = FieldEvent::Tick {
+                tick,
+                total_energy: 0,
+            };
+            newest.on_event(&event);
+            oldest.on_event(&event);
+        }
+
+        let ticks = |a: &AsyncObserverAdapter| -> Vec<u64> {
+            a.queue
+                .events
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|e| e.tick())
+                .collect()
+        };
+        assert_eq!(newest.dropped(), 3);
+        assert_eq!(ticks(&newest), vec![0, 1]);
+        assert_eq!(oldest.dropped(), 3);
+        assert_eq!(ticks(&oldest), vec![3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_tokio_channel_observer() {
+        let mut field = TemporalField::new(FieldConfig::new(32, 10, 128));
+        field
+            .monitor_region(MonitoredRegion::new("a", 0..32, 50_000))
+            .unwrap();
+
+        let (tx, mut rx) = mpsc::channel(8);
+        field.subscribe(Arc::new(TokioChannelObserver::new(tx)));
+
+        field.write_region(&[Signal::positive_amplified(128, 1); 32], 0..32);
+        field.tick_n(4);
+
+        assert!(matches!(
+            rx.recv().await,
+            Some(FieldEvent::RegionActive { .. })
+        ));
+        assert!(matches!(
+            rx.recv().await,
+            Some(FieldEvent::RegionQuiet { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_tokio_channel_drop_policy() {
+        let tick = |tick| FieldEvent::Tick {
+            tick,
+            total_energy: 0,
+        };
+
+        let (tx, mut rx) = mpsc::channel(2);
+        let newest = TokioChannelObserver::new(tx);
+        for t in 0..5 {
+            newest.on_event(&tick(t));
+        }
+        assert_eq!(newest.dropped(), 3);
+        assert_eq!(rx.recv().await.map(|e| e.tick()), Some(0));
+        assert_eq!(rx.recv().await.map(|e| e.tick()), Some(1));
+
+        let (tx, mut rx) = mpsc::channel(2);
+        let oldest = TokioChannelObserver::new(tx).with_policy(DropPolicy::DropOldest);
+        for t in 0..6 {
+            oldest.on_event(&tick(t));
+        }
+        // Channel holds 0, 1; backlog keeps the newest two of 2..6
+        assert_eq!(oldest.dropped(), 2);
+        assert_eq!(rx.recv().await.map(|e| e.tick()), Some(0));
+        assert_eq!(rx.recv().await.map(|e| e.tick()), Some(1));
+        oldest.on_event(&tick(6));
+        let received: Vec<u64> = std::iter::from_fn(|| rx.try_recv().ok())
+            .map(|e| e.tick())
+            .collect();
+        assert_eq!(received, vec![4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_tokio_channel_backlog_flushes_without_new_events() {
+        let (tx, mut rx) = mpsc::channel(2);
+        let observer = TokioChannelObserver::new(tx).with_policy(DropPolicy::DropOldest);
+        for tick in 0..4 {
+            observer.on_event(&FieldEvent::Tick {
+                tick,
+                total_energy: 0,
+            });
+        }
+
+        // No further event: the backlog (2, 3) still reaches the receiver
+        let received = tokio::time::timeout(std::time::Duration::from_secs(1), async {
+            let mut received = Vec::new();
+            while received.len() < 4 {
+                received.push(rx.recv().await.unwrap().tick());
+            }
+            received
+        })
+        .await
+        .expect("backlog should flush");
+        assert_eq!(received, vec![0, 1, 2, 3]);
+        assert_eq!(observer.dropped(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_watch_observer_latest_state() {
+        let mut field = TemporalField::new(FieldConfig::new(64, 10, 128));
+        field
+            .monitor_region(MonitoredRegion::new("a", 0..32, 50_000))
+            .unwrap();
+        field
+            .monitor_region(MonitoredRegion::new("b", 32..64, 50_000))
+            .unwrap();
+
+        let (observer, mut rx) = WatchObserver::new();
+        field.subscribe(Arc::new(observer));
+
+        let signals = vec![Signal::positive_amplified(128, 1); 32];
+        field.write_region(&signals, 0..32);
+        field.write_region(&signals, 32..64);
+        field.tick_n(4); // both decay to quiet
+        field.write_region(&signals, 0..32);
+
+        rx.changed().await.unwrap();
+        let states = rx.borrow_and_update();
+        assert!(matches!(states["a"], FieldEvent::RegionActive { .. }));
+        assert!(matches!(states["b"], FieldEvent::RegionQuiet { .. }));
+    }
+}
@@ -3,7 +3,12 @@
 //! ASTRO_004 compliant: Uses Signal (polarity × magnitude × multiplier) throughout.
 //! No floats in neural computation paths.
 
-use std::ops::Range;
+use crate::config::DecayMode;
+use crate::observer::PoolMode;
+use crate::region::RegionSpec;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
 use ternary_signal::Signal;
 
 #[cfg(feature = "serde")]
@@ -16,6 +21,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// Arithmetic operations use the full effective value: `polarity × magnitude × multiplier`
 /// (range ±65,025). Results are decomposed back into (p, m, k) via `Signal::from_current`.
+///
+/// The read operations are shared with [`FrameRef`], the borrowed view
+/// that [`TemporalField`](crate::TemporalField) hands out for its frames.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FieldVector {
@@ -35,6 +43,19 @@ impl FieldVector {
         Self { signals }
     }
 
+    /// Borrow as a read-only [`FrameRef`].
+    #[inline]
+    pub fn frame(&self) -> FrameRef<'_> {
+        FrameRef {
+            signals: &self.signals,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn frame_mut(&mut self) -> FrameMut<'_> {
+        FrameMut::new(&mut self.signals)
+    }
+
     /// Get dimensions.
     #[inline]
     pub fn dims(&self) -> usize {
@@ -49,65 +70,914 @@ impl FieldVector {
 
     /// Set Signal at index.
     #[inline]
-    pub fn set(&mut self, idx: usize, signal: Signal) {
-        self.signals[idx] = signal;
+    pub fn set(&mut self, idx: usize, signal: Signal) {
+        self.signals[idx] = signal;
+    }
+
+    /// Get the full effective value: `polarity × magnitude × multiplier` (±65,025).
+    #[inline]
+    pub fn get_current(&self, idx: usize) -> i32 {
+        self.signals[idx].current()
+    }
+
+    /// Set from a signed i32 value using the full p×m×k range (±65,025).
+    #[inline]
+    pub fn set_current(&mut self, idx: usize, value: i32) {
+        self.signals[idx] = Signal::from_current(value);
+    }
+
+    /// Get as signed i16 (polarity × magnitude only, ignores multiplier).
+    ///
+    /// **Deprecated in favor of [`get_current`]** which uses the full range.
+    /// Retained for backward compatibility with code that needs the narrow range.
+    #[inline]
+    pub fn get_i16(&self, idx: usize) -> i16 {
+        self.frame().get_i16(idx)
+    }
+
+    /// Set from signed i16 value (clamped to ±255, multiplier=1).
+    ///
+    /// **Deprecated in favor of [`set_current`]** which uses the full range.
+    /// Retained for backward compatibility.
+    #[inline]
+    pub fn set_i16(&mut self, idx: usize, value: i16) {
+        self.frame_mut().set_i16(idx, value);
+    }
+
+    /// Decay all values toward zero.
+    /// retention: u8 where 255 = 1.0 (no decay), 230 ≈ 0.90
+    ///
+    /// Decays the effective value (p×m×k), then re-encodes into Signal.
+    /// This preserves the full dynamic range during decay.
+    pub fn decay(&mut self, retention: u8) {
+        self.frame_mut().decay(retention);
+    }
+
+    /// Decay all values toward zero, rounding each result up or down at
+    /// random in proportion to its fractional part.
+    ///
+    /// The expected result is exactly `value × retention / 255`, so small
+    /// values decay at the ideal rate on average instead of snapping to
+    /// zero or sticking at a truncation fixed point. `rng_state` is a
+    /// xorshift64 state, advanced once per nonzero dim; a zero state is
+    /// replaced with a fixed nonzero seed.
+    pub fn decay_dithered(&mut self, retention: u8, rng_state: &mut u64) {
+        self.frame_mut().decay_dithered(retention, rng_state);
+    }
+
+    /// Decay under a decay mode with dithered rounding; see
+    /// [`decay_dithered`](Self::decay_dithered). Linear decay has no
+    /// fractional part and is applied as usual.
+    pub fn decay_dithered_with(&mut self, mode: DecayMode, rng_state: &mut u64) {
+        self.frame_mut().decay_dithered_with(mode, rng_state);
+    }
+
+    /// Decay all values toward zero under a decay mode.
+    ///
+    /// Works on effective values (p×m×k) like [`decay`](Self::decay).
+    pub fn decay_with(&mut self, mode: DecayMode) {
+        self.frame_mut().decay_with(mode);
+    }
+
+    /// Decay by `ticks` ticks of a decay mode in one pass.
+    ///
+    /// Multiplicative retention is raised to the `ticks`-th power in 32-bit
+    /// fixed point and applied once (truncating), so results match the
+    /// ideal `value × (r/255)ⁿ` rather than `ticks` truncating
+    /// [`decay_with`](Self::decay_with) calls, which end slightly lower.
+    /// Linear decay subtracts `step × ticks`; Floor zeroes what ends below
+    /// its minimum.
+    pub fn decay_ticks(&mut self, mode: DecayMode, ticks: u64) {
+        self.frame_mut().decay_ticks(mode, ticks);
+    }
+
+    /// Add another vector (saturating at ±65,025).
+    pub fn add(&mut self, other: &FieldVector) {
+        self.frame_mut().add(other.frame());
+    }
+
+    /// Keep, per dim, whichever of the two signals has the larger effective
+    /// magnitude (this vector's on ties).
+    ///
+    /// Dims past either vector's end are left unchanged.
+    pub fn merge_max(&mut self, other: &FieldVector) {
+        self.frame_mut().merge_max(other.frame());
+    }
+
+    /// Add Signals to a range (saturating at ±65,025).
+    pub fn add_to_range(&mut self, signals: &[Signal], range: Range<usize>) {
+        self.frame_mut().add_to_range(signals, range);
+    }
+
+    /// Add Signals to the dims of a region spec, in order (saturating at ±65,025).
+    ///
+    /// Extra signals and out-of-bounds dims are ignored.
+    pub fn add_to_indices(&mut self, signals: &[Signal], spec: &RegionSpec) {
+        self.frame_mut().add_to_indices(signals, spec);
+    }
+
+    /// Add Signals from an iterator to a range, in order (saturating at
+    /// ±65,025).
+    ///
+    /// Takes at most `range.len()` items; extras are left unconsumed, and a
+    /// short iterator leaves the rest of the range untouched. Out-of-bounds
+    /// dims are ignored, like [`add_to_range`](Self::add_to_range).
+    pub fn add_to_range_iter(
+        &mut self,
+        signals: impl IntoIterator<Item = Signal>,
+        range: Range<usize>,
+    ) {
+        self.frame_mut().add_to_range_iter(signals, range);
+    }
+
+    /// Set Signals from an iterator in a range, in order.
+    ///
+    /// Same truncation as [`add_to_range_iter`](Self::add_to_range_iter).
+    pub fn set_range_iter(
+        &mut self,
+        signals: impl IntoIterator<Item = Signal>,
+        range: Range<usize>,
+    ) {
+        self.frame_mut().set_range_iter(signals, range);
+    }
+
+    /// Add Signals at individual dims (saturating at ±65,025).
+    ///
+    /// Out-of-bounds indices are ignored; repeated indices accumulate.
+    pub fn add_sparse(&mut self, entries: &[(usize, Signal)]) {
+        self.frame_mut().add_sparse(entries);
+    }
+
+    /// Set Signals in a range.
+    pub fn set_range(&mut self, signals: &[Signal], range: Range<usize>) {
+        self.frame_mut().set_range(signals, range);
+    }
+
+    /// Set Signals at the dims of a region spec, in order.
+    ///
+    /// Extra signals and out-of-bounds dims are ignored.
+    pub fn set_indices(&mut self, signals: &[Signal], spec: &RegionSpec) {
+        self.frame_mut().set_indices(signals, spec);
+    }
+
+    /// Get Signals from a range.
+    pub fn get_range(&self, range: Range<usize>) -> Vec<Signal> {
+        self.frame().get_range(range)
+    }
+
+    /// Get Signals at the dims of a region spec, in order (out-of-bounds dims skipped).
+    pub fn get_indices(&self, spec: &RegionSpec) -> Vec<Signal> {
+        self.frame().get_indices(spec)
+    }
+
+    /// Compute energy (sum of squared effective magnitudes) in a range.
+    /// Returns u64 to prevent overflow (max per element: 65025² ≈ 4.2B).
+    pub fn range_energy(&self, range: Range<usize>) -> u64 {
+        self.frame().range_energy(range)
+    }
+
+    /// Compute energy over the dims of a region spec (out-of-bounds dims skipped).
+    pub fn indices_energy(&self, spec: &RegionSpec) -> u64 {
+        self.frame().indices_energy(spec)
+    }
+
+    /// Sum of effective magnitudes in a range, ignoring polarity (saturating).
+    pub fn range_l1(&self, range: Range<usize>) -> u32 {
+        self.frame().range_l1(range)
+    }
+
+    /// Sum of effective magnitudes over the dims of a region spec (saturating).
+    pub fn indices_l1(&self, spec: &RegionSpec) -> u32 {
+        self.frame().indices_l1(spec)
+    }
+
+    /// Signed sum of effective values in a range: positive and negative
+    /// signals cancel.
+    pub fn range_signed_sum(&self, range: Range<usize>) -> i64 {
+        self.frame().range_signed_sum(range)
+    }
+
+    /// Signed sum of effective values over the dims of a region spec.
+    pub fn indices_signed_sum(&self, spec: &RegionSpec) -> i64 {
+        self.frame().indices_signed_sum(spec)
+    }
+
+    /// Magnitude-weighted mean index of a range, rounded to the nearest dim.
+    ///
+    /// Σ(i × magnitude) / Σ(magnitude) over effective magnitudes, with `i`
+    /// the absolute dim index. Returns None if the range is all zero.
+    pub fn range_centroid(&self, range: Range<usize>) -> Option<usize> {
+        self.frame().range_centroid(range)
+    }
+
+    /// Magnitude-weighted mean index over the dims of a region spec.
+    ///
+    /// See [`range_centroid`](Self::range_centroid).
+    pub fn indices_centroid(&self, spec: &RegionSpec) -> Option<usize> {
+        self.frame().indices_centroid(spec)
+    }
+
+    /// Get the `k` strongest nonzero dims of a range as `(index, signal)`.
+    ///
+    /// Sorted by effective magnitude, strongest first; ties go to the lower
+    /// index. Only the selected dims are sorted, so small `k` stays cheap
+    /// on wide ranges.
+    pub fn top_k(&self, range: Range<usize>, k: usize) -> Vec<(usize, Signal)> {
+        self.frame().top_k(range, k)
+    }
+
+    /// Pool a range into bins of `factor` consecutive dims, one signal per bin.
+    ///
+    /// When `factor` does not divide the range, the last bin holds the
+    /// remaining dims and is pooled over just those, so the result has
+    /// `ceil(len / factor)` signals. The range is clamped to the vector;
+    /// a `factor` of 0 is treated as 1.
+    pub fn downsample(&self, range: Range<usize>, factor: usize, mode: PoolMode) -> Vec<Signal> {
+        self.frame().downsample(range, factor, mode)
+    }
+
+    /// Add a coarse pattern across a range, broadcasting each signal over
+    /// `ceil(len / signals.len())` consecutive dims (saturating at ±65,025).
+    ///
+    /// The inverse of [`downsample`](Self::downsample): when the lengths do
+    /// not divide, the last signal covers the remaining dims only. Signals
+    /// that fall past the range's end are ignored.
+    pub fn add_upsampled(&mut self, signals: &[Signal], range: Range<usize>) {
+        self.frame_mut().add_upsampled(signals, range);
+    }
+
+    /// Move the signals within a range `offset` positions (positive toward
+    /// the range's end), zeroing the dims left vacated.
+    ///
+    /// Signals shifted past either end of the range are dropped. The range
+    /// is clamped to the vector.
+    pub fn shift_range(&mut self, range: Range<usize>, offset: isize) {
+        self.frame_mut().shift_range(range, offset);
+    }
+
+    /// Circularly shift the signals within a range `k` positions toward its
+    /// end; the last `k` wrap around to the start.
+    ///
+    /// The range is clamped to the vector. Shifting by the range's length
+    /// is the identity.
+    pub fn rotate(&mut self, range: Range<usize>, k: usize) {
+        self.frame_mut().rotate(range, k);
+    }
+
+    /// Reorder the signals within a range: position `i` takes the signal
+    /// previously at `range.start + perm[i]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `perm` is not a permutation of `0..range.len()`, or the
+    /// range extends past the vector.
+    pub fn permute(&mut self, range: Range<usize>, perm: &[usize]) {
+        self.frame_mut().permute(range, perm);
+    }
+
+    /// Bind two equal-length ranges dim by dim: the sign product with the
+    /// smaller effective magnitude (the integer analogue of HDC binding).
+    ///
+    /// Binding is its own inverse up to magnitude: binding the result with
+    /// `b` again restores `a`'s polarities wherever `b` is nonzero. Dims
+    /// past the shorter range (or the vector's end) are skipped.
+    pub fn bind(&self, a: Range<usize>, b: Range<usize>) -> Vec<Signal> {
+        self.frame().bind(a, b)
+    }
+
+    /// Bundle a range across several vectors by majority vote.
+    ///
+    /// Per dim, sums polarity × magnitude over `vectors` (i32): the result
+    /// takes the sign of the sum and the sum divided by the vector count as
+    /// magnitude, so dims all vectors agree on keep their average strength
+    /// while conflicting dims cancel toward zero. Zero-sum dims, and all
+    /// dims when `vectors` is empty, are [`Signal::ZERO`]. The range is
+    /// clamped to the narrowest vector.
+    pub fn bundle(vectors: &[&FieldVector], range: Range<usize>) -> Vec<Signal> {
+        let frames: Vec<FrameRef<'_>> = vectors.iter().map(|v| v.frame()).collect();
+        FrameRef::bundle(&frames, range)
+    }
+
+    /// Keep the `k` strongest dims of a range and zero the rest
+    /// (k-winners-take-all).
+    ///
+    /// Ties at the k-th magnitude keep the lower indices.
+    pub fn kwta(&mut self, range: Range<usize>, k: usize) {
+        self.frame_mut().kwta(range, k);
+    }
+
+    /// Dot product with another vector over a range, using the narrow
+    /// `polarity × magnitude` values (see [`get_i16`](Self::get_i16)).
+    ///
+    /// Dims past either vector's end are skipped.
+    pub fn dot_range(&self, other: &FieldVector, range: Range<usize>) -> i64 {
+        self.frame().dot_range(other.frame(), range)
+    }
+
+    /// Pattern similarity between two equal-length ranges, 0..=1000.
+    ///
+    /// Averages two integer measures over effective values:
+    /// - sign agreement: dims with matching nonzero polarity, per mille of
+    ///   dims where either side is nonzero
+    /// - normalized dot product (cosine), per mille, negative clamped to 0
+    ///
+    /// Returns 0 if both ranges are silent.
+    pub fn similarity_permille(&self, range_a: Range<usize>, range_b: Range<usize>) -> u16 {
+        self.frame().similarity_permille(range_a, range_b)
+    }
+
+    /// Pattern similarity between two equal-length region specs, 0..=1000.
+    ///
+    /// Dims are paired in spec order; see
+    /// [`similarity_permille`](Self::similarity_permille).
+    pub fn indices_similarity_permille(&self, a: &RegionSpec, b: &RegionSpec) -> u16 {
+        self.frame().indices_similarity_permille(a, b)
+    }
+
+    /// Pattern similarity between the dims of a region spec and a stored
+    /// pattern, 0..=1000.
+    ///
+    /// Dims are paired with `pattern` in spec order; see
+    /// [`similarity_permille`](Self::similarity_permille).
+    pub fn pattern_similarity_permille(&self, spec: &RegionSpec, pattern: &[Signal]) -> u16 {
+        self.frame().pattern_similarity_permille(spec, pattern)
+    }
+
+    /// Check if range is active (energy above threshold).
+    pub fn range_active(&self, range: Range<usize>, threshold: u64) -> bool {
+        self.frame().range_active(range, threshold)
+    }
+
+    /// Count signals in a range clipped at the ±65,025 ceiling.
+    pub fn saturated_count(&self, range: Range<usize>) -> usize {
+        self.frame().saturated_count(range)
+    }
+
+    /// Count signals at the dims of a region spec clipped at the ±65,025 ceiling.
+    pub fn indices_saturated_count(&self, spec: &RegionSpec) -> usize {
+        self.frame().indices_saturated_count(spec)
+    }
+
+    /// Count nonzero signals in a range: `(nonzero, total)`.
+    pub fn range_occupancy(&self, range: Range<usize>) -> (usize, usize) {
+        self.frame().range_occupancy(range)
+    }
+
+    /// Count nonzero signals at the dims of a region spec: `(nonzero, total)`.
+    ///
+    /// Out-of-bounds dims are not counted in either.
+    pub fn indices_occupancy(&self, spec: &RegionSpec) -> (usize, usize) {
+        self.frame().indices_occupancy(spec)
+    }
+
+    /// Check if all signals are zero.
+    pub fn is_zero(&self) -> bool {
+        self.frame().is_zero()
+    }
+
+    /// Count non-zero signals.
+    pub fn non_zero_count(&self) -> usize {
+        self.frame().non_zero_count()
+    }
+
+    /// Get maximum effective magnitude.
+    pub fn max_magnitude(&self) -> u16 {
+        self.frame().max_magnitude()
+    }
+
+    /// Scale all values by factor (u8 where 255 = 1.0).
+    pub fn scale(&mut self, factor: u8) {
+        self.frame_mut().scale(factor);
+    }
+
+    /// Scale values in a range by factor (u8 where 255 = 1.0).
+    pub fn scale_range(&mut self, range: Range<usize>, factor: u8) {
+        self.frame_mut().scale_range(range, factor);
+    }
+
+    /// Scale values at the dims of a region spec by factor (u8 where 255 = 1.0).
+    ///
+    /// Out-of-bounds dims are ignored.
+    pub fn scale_indices(&mut self, spec: &RegionSpec, factor: u8) {
+        self.frame_mut().scale_indices(spec, factor);
+    }
+
+    /// Get slice reference for direct access.
+    pub fn as_slice(&self) -> &[Signal] {
+        &self.signals
+    }
+
+    /// Get mutable slice reference.
+    pub fn as_mut_slice(&mut self) -> &mut [Signal] {
+        &mut self.signals
+    }
+}
+
+/// A borrowed, read-only frame: the signals of a [`FieldVector`] or of one
+/// frame in a [`TemporalField`](crate::TemporalField).
+///
+/// Offers the same read operations as `FieldVector` (documented there) and
+/// derefs to `[Signal]`. Copy it freely; [`to_vector`](Self::to_vector)
+/// makes an owned copy.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrameRef<'a> {
+    signals: &'a [Signal],
+}
+
+impl<'a> FrameRef<'a> {
+    /// View a slice of signals as a frame.
+    #[inline]
+    pub fn new(signals: &'a [Signal]) -> Self {
+        Self { signals }
+    }
+
+    /// Copy into an owned [`FieldVector`].
+    pub fn to_vector(self) -> FieldVector {
+        FieldVector::from_signals(self.signals.to_vec())
+    }
+
+    /// Get the underlying signals.
+    #[inline]
+    pub fn as_slice(self) -> &'a [Signal] {
+        self.signals
+    }
+
+    /// See [`FieldVector::dims`].
+    #[inline]
+    pub fn dims(self) -> usize {
+        self.signals.len()
+    }
+
+    /// See [`FieldVector::get`].
+    #[inline]
+    pub fn get(self, idx: usize) -> Signal {
+        self.signals[idx]
+    }
+
+    /// See [`FieldVector::get_current`].
+    #[inline]
+    pub fn get_current(self, idx: usize) -> i32 {
+        self.signals[idx].current()
+    }
+
+    /// See [`FieldVector::get_i16`].
+    #[inline]
+    pub fn get_i16(self, idx: usize) -> i16 {
+        let s = self.signals[idx];
+        (s.polarity as i16) * (s.magnitude as i16)
+    }
+
+    /// See [`FieldVector::get_range`].
+    pub fn get_range(self, range: Range<usize>) -> Vec<Signal> {
+        let end = range.end.min(self.signals.len());
+        self.signals[range.start.min(end)..end].to_vec()
+    }
+
+    /// See [`FieldVector::get_indices`].
+    pub fn get_indices(self, spec: &RegionSpec) -> Vec<Signal> {
+        spec.iter()
+            .filter_map(|i| self.signals.get(i).copied())
+            .collect()
+    }
+
+    /// See [`FieldVector::range_energy`].
+    pub fn range_energy(self, range: Range<usize>) -> u64 {
+        let end = range.end.min(self.signals.len());
+        energy_of(&self.signals[range.start.min(end)..end])
+    }
+
+    /// See [`FieldVector::indices_energy`].
+    pub fn indices_energy(self, spec: &RegionSpec) -> u64 {
+        spec.iter()
+            .filter_map(|i| self.signals.get(i))
+            .map(|s| {
+                let eff = s.effective_magnitude() as u64;
+                eff * eff
+            })
+            .sum()
+    }
+
+    /// See [`FieldVector::range_l1`].
+    pub fn range_l1(self, range: Range<usize>) -> u32 {
+        self.indices_l1(&RegionSpec::Contiguous(range))
+    }
+
+    /// See [`FieldVector::indices_l1`].
+    pub fn indices_l1(self, spec: &RegionSpec) -> u32 {
+        spec.iter()
+            .filter_map(|i| self.signals.get(i))
+            .fold(0u32, |sum, s| {
+                sum.saturating_add(s.effective_magnitude() as u32)
+            })
+    }
+
+    /// See [`FieldVector::range_signed_sum`].
+    pub fn range_signed_sum(self, range: Range<usize>) -> i64 {
+        self.indices_signed_sum(&RegionSpec::Contiguous(range))
+    }
+
+    /// See [`FieldVector::indices_signed_sum`].
+    pub fn indices_signed_sum(self, spec: &RegionSpec) -> i64 {
+        spec.iter()
+            .filter_map(|i| self.signals.get(i))
+            .map(|s| s.current() as i64)
+            .sum()
+    }
+
+    /// See [`FieldVector::range_centroid`].
+    pub fn range_centroid(self, range: Range<usize>) -> Option<usize> {
+        self.indices_centroid(&RegionSpec::Contiguous(range))
+    }
+
+    /// See [`FieldVector::indices_centroid`].
+    pub fn indices_centroid(self, spec: &RegionSpec) -> Option<usize> {
+        let mut moment: u128 = 0;
+        let mut mass: u64 = 0;
+        for i in spec.iter() {
+            let Some(s) = self.signals.get(i) else {
+                continue;
+            };
+            let m = s.effective_magnitude() as u64;
+            moment += i as u128 * m as u128;
+            mass += m;
+        }
+        if mass == 0 {
+            return None;
+        }
+        Some(((moment + mass as u128 / 2) / mass as u128) as usize)
+    }
+
+    /// See [`FieldVector::top_k`].
+    pub fn top_k(self, range: Range<usize>, k: usize) -> Vec<(usize, Signal)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let range = range.start.min(self.dims())..range.end.min(self.dims());
+        let mut dims: Vec<(usize, Signal)> = range
+            .map(|i| (i, self.signals[i]))
+            .filter(|(_, s)| s.effective_magnitude() > 0)
+            .collect();
+        let strongest_first = |a: &(usize, Signal), b: &(usize, Signal)| {
+            b.1.effective_magnitude()
+                .cmp(&a.1.effective_magnitude())
+                .then(a.0.cmp(&b.0))
+        };
+        if k < dims.len() {
+            dims.select_nth_unstable_by(k - 1, strongest_first);
+            dims.truncate(k);
+        }
+        dims.sort_unstable_by(strongest_first);
+        dims
+    }
+
+    /// See [`FieldVector::downsample`].
+    pub fn downsample(self, range: Range<usize>, factor: usize, mode: PoolMode) -> Vec<Signal> {
+        let range = range.start.min(self.dims())..range.end.min(self.dims());
+        self.signals[range]
+            .chunks(factor.max(1))
+            .map(|bin| mode.pool(bin.iter().copied()))
+            .collect()
+    }
+
+    /// See [`FieldVector::bind`].
+    pub fn bind(self, a: Range<usize>, b: Range<usize>) -> Vec<Signal> {
+        let end = |r: Range<usize>| r.start.min(self.dims())..r.end.min(self.dims());
+        end(a)
+            .zip(end(b))
+            .map(|(i, j)| {
+                let (x, y) = (self.get_current(i), self.get_current(j));
+                Signal::from_current(x.signum() * y.signum() * x.abs().min(y.abs()))
+            })
+            .collect()
+    }
+
+    /// See [`FieldVector::bundle`].
+    pub fn bundle(frames: &[FrameRef<'_>], range: Range<usize>) -> Vec<Signal> {
+        let dims = frames.iter().map(|f| f.dims()).min().unwrap_or(0);
+        let n = frames.len().max(1) as i32;
+        (range.start.min(dims)..range.end.min(dims))
+            .map(|i| {
+                let sum: i32 = frames.iter().map(|f| f.get_i16(i) as i32).sum();
+                Signal::from_current(sum / n)
+            })
+            .collect()
+    }
+
+    /// See [`FieldVector::dot_range`].
+    pub fn dot_range(self, other: FrameRef<'_>, range: Range<usize>) -> i64 {
+        let end = range.end.min(self.dims()).min(other.dims());
+        (range.start.min(end)..end)
+            .map(|i| self.get_i16(i) as i64 * other.get_i16(i) as i64)
+            .sum()
+    }
+
+    /// See [`FieldVector::similarity_permille`].
+    pub fn similarity_permille(self, range_a: Range<usize>, range_b: Range<usize>) -> u16 {
+        self.indices_similarity_permille(
+            &RegionSpec::Contiguous(range_a),
+            &RegionSpec::Contiguous(range_b),
+        )
+    }
+
+    /// See [`FieldVector::indices_similarity_permille`].
+    pub fn indices_similarity_permille(self, a: &RegionSpec, b: &RegionSpec) -> u16 {
+        debug_assert_eq!(a.len(), b.len());
+        similarity_permille_of(
+            a.iter()
+                .zip(b.iter())
+                .take_while(|&(i, j)| i < self.dims() && j < self.dims())
+                .map(|(i, j)| (self.get_current(i), self.get_current(j))),
+        )
+    }
+
+    /// See [`FieldVector::pattern_similarity_permille`].
+    pub fn pattern_similarity_permille(self, spec: &RegionSpec, pattern: &[Signal]) -> u16 {
+        similarity_permille_of(
+            spec.iter()
+                .zip(pattern)
+                .take_while(|&(i, _)| i < self.dims())
+                .map(|(i, s)| (self.get_current(i), s.current())),
+        )
+    }
+
+    /// See [`FieldVector::range_active`].
+    pub fn range_active(self, range: Range<usize>, threshold: u64) -> bool {
+        self.range_energy(range) > threshold
+    }
+
+    /// See [`FieldVector::saturated_count`].
+    pub fn saturated_count(self, range: Range<usize>) -> usize {
+        self.indices_saturated_count(&RegionSpec::Contiguous(range))
+    }
+
+    /// See [`FieldVector::indices_saturated_count`].
+    pub fn indices_saturated_count(self, spec: &RegionSpec) -> usize {
+        spec.iter()
+            .filter_map(|i| self.signals.get(i))
+            .filter(|s| s.effective_magnitude() >= 65025)
+            .count()
+    }
+
+    /// See [`FieldVector::range_occupancy`].
+    pub fn range_occupancy(self, range: Range<usize>) -> (usize, usize) {
+        self.indices_occupancy(&RegionSpec::Contiguous(range))
+    }
+
+    /// See [`FieldVector::indices_occupancy`].
+    pub fn indices_occupancy(self, spec: &RegionSpec) -> (usize, usize) {
+        spec.iter()
+            .filter_map(|i| self.signals.get(i))
+            .fold((0, 0), |(nonzero, total), s| {
+                (nonzero + (s.current() != 0) as usize, total + 1)
+            })
+    }
+
+    /// See [`FieldVector::is_zero`].
+    pub fn is_zero(self) -> bool {
+        self.signals.iter().all(|s| s.magnitude == 0)
+    }
+
+    /// See [`FieldVector::non_zero_count`].
+    pub fn non_zero_count(self) -> usize {
+        self.signals.iter().filter(|s| s.magnitude > 0).count()
+    }
+
+    /// See [`FieldVector::max_magnitude`].
+    pub fn max_magnitude(self) -> u16 {
+        self.signals
+            .iter()
+            .map(|s| s.effective_magnitude())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl core::ops::Deref for FrameRef<'_> {
+    type Target = [Signal];
+
+    fn deref(&self) -> &[Signal] {
+        self.signals
+    }
+}
+
+impl<'a> From<&'a FieldVector> for FrameRef<'a> {
+    fn from(vector: &'a FieldVector) -> Self {
+        vector.frame()
+    }
+}
+
+/// A mutable frame: the write side of [`FrameRef`], shared by
+/// [`FieldVector`] and the field's frame storage.
+///
+/// A tracked frame keeps a count of its non-zero signals (magnitude > 0,
+/// as [`FrameRef::non_zero_count`] counts them) in step with every write:
+/// per changed dim for targeted writes, by a recount for whole-frame ones.
+pub(crate) struct FrameMut<'a> {
+    signals: &'a mut [Signal],
+    nonzero: Option<&'a mut usize>,
+}
+
+impl<'a> FrameMut<'a> {
+    #[inline]
+    pub(crate) fn new(signals: &'a mut [Signal]) -> Self {
+        Self {
+            signals,
+            nonzero: None,
+        }
+    }
+
+    /// A frame whose non-zero count is `nonzero` (which must be right on
+    /// entry) and is kept right by every write.
+    #[inline]
+    pub(crate) fn tracked(signals: &'a mut [Signal], nonzero: &'a mut usize) -> Self {
+        Self {
+            signals,
+            nonzero: Some(nonzero),
+        }
+    }
+
+    /// Store `s` at `idx`, adjusting the non-zero count.
+    #[inline]
+    fn put(&mut self, idx: usize, s: Signal) {
+        let slot = &mut self.signals[idx];
+        if let Some(n) = self.nonzero.as_deref_mut() {
+            *n = *n + usize::from(s.magnitude > 0) - usize::from(slot.magnitude > 0);
+        }
+        *slot = s;
+    }
+
+    /// Record the non-zero count a whole-frame kernel counted as it went.
+    #[inline]
+    fn set_nonzero(&mut self, nonzero: usize) {
+        if let Some(n) = self.nonzero.as_deref_mut() {
+            *n = nonzero;
+        }
+    }
+
+    /// Recount non-zero signals after a whole-frame write.
+    fn recount(&mut self) {
+        let nonzero = self.frame().non_zero_count();
+        self.set_nonzero(nonzero);
+    }
+
+    /// Rewrite the dims of `range` through `f`, adjusting the non-zero count
+    /// by recounting only that range.
+    fn rewrite_range(&mut self, range: Range<usize>, f: impl FnOnce(&mut [Signal])) {
+        let dims = self.signals.len();
+        let slice = &mut self.signals[range.start.min(dims)..range.end.min(dims)];
+        let count = |slice: &[Signal]| slice.iter().filter(|s| s.magnitude > 0).count();
+        let before = self.nonzero.is_some().then(|| count(slice));
+        f(slice);
+        if let (Some(n), Some(before)) = (self.nonzero.as_deref_mut(), before) {
+            *n = *n + count(slice) - before;
+        }
+    }
+
+    #[inline]
+    pub(crate) fn frame(&self) -> FrameRef<'_> {
+        FrameRef {
+            signals: self.signals,
+        }
     }
 
-    /// Get the full effective value: `polarity × magnitude × multiplier` (±65,025).
     #[inline]
-    pub fn get_current(&self, idx: usize) -> i32 {
+    pub(crate) fn get_current(&self, idx: usize) -> i32 {
         self.signals[idx].current()
     }
 
-    /// Set from a signed i32 value using the full p×m×k range (±65,025).
     #[inline]
-    pub fn set_current(&mut self, idx: usize, value: i32) {
-        self.signals[idx] = Signal::from_current(value);
+    pub(crate) fn set_current(&mut self, idx: usize, value: i32) {
+        self.put(idx, Signal::from_current(value));
     }
 
-    /// Get as signed i16 (polarity × magnitude only, ignores multiplier).
-    ///
-    /// **Deprecated in favor of [`get_current`]** which uses the full range.
-    /// Retained for backward compatibility with code that needs the narrow range.
     #[inline]
-    pub fn get_i16(&self, idx: usize) -> i16 {
-        let s = self.signals[idx];
-        (s.polarity as i16) * (s.magnitude as i16)
+    pub(crate) fn set_i16(&mut self, idx: usize, value: i16) {
+        self.put(idx, Signal::from_current((value as i32).clamp(-255, 255)));
     }
 
-    /// Set from signed i16 value (clamped to ±255, multiplier=1).
-    ///
-    /// **Deprecated in favor of [`set_current`]** which uses the full range.
-    /// Retained for backward compatibility.
-    #[inline]
-    pub fn set_i16(&mut self, idx: usize, value: i16) {
-        self.signals[idx] = Signal::from_signed_i32(value as i32);
+    /// Overwrite every signal with `other`'s (same length).
+    pub(crate) fn copy_from(&mut self, other: FrameRef<'_>) {
+        self.signals.copy_from_slice(other.signals);
+        self.recount();
     }
 
-    /// Decay all values toward zero.
-    /// retention: u8 where 255 = 1.0 (no decay), 230 ≈ 0.90
-    ///
-    /// Decays the effective value (p×m×k), then re-encodes into Signal.
-    /// This preserves the full dynamic range during decay.
-    pub fn decay(&mut self, retention: u8) {
-        for s in &mut self.signals {
+    pub(crate) fn clear(&mut self) {
+        self.signals.fill(Signal::ZERO);
+        self.set_nonzero(0);
+    }
+
+    pub(crate) fn decay(&mut self, retention: u8) {
+        #[cfg(feature = "simd")]
+        let nonzero = crate::simd::decay(self.signals, retention);
+        #[cfg(not(feature = "simd"))]
+        let nonzero = decay_scalar(self.signals, retention);
+        self.set_nonzero(nonzero);
+    }
+
+    pub(crate) fn decay_dithered(&mut self, retention: u8, rng_state: &mut u64) {
+        if *rng_state == 0 {
+            *rng_state = DEFAULT_DITHER_SEED;
+        }
+        let mut nonzero = 0;
+        for s in self.signals.iter_mut() {
             let current = s.current();
-            if current == 0 {
-                continue;
+            if current != 0 {
+                let product = current.unsigned_abs() as u64 * retention as u64;
+                let mut magnitude = product / 255;
+                if xorshift64(rng_state) % 255 < product % 255 {
+                    magnitude += 1;
+                }
+                *s = Signal::from_current(current.signum() * magnitude as i32);
             }
-            // Apply retention to the effective value
-            let decayed = (current as i64 * retention as i64 / 255) as i32;
-            if decayed == 0 {
-                *s = Signal::ZERO;
-            } else {
-                *s = Signal::from_current(decayed);
+            nonzero += usize::from(s.magnitude > 0);
+        }
+        self.set_nonzero(nonzero);
+    }
+
+    pub(crate) fn decay_dithered_with(&mut self, mode: DecayMode, rng_state: &mut u64) {
+        self.apply_decay(mode, Some(rng_state));
+    }
+
+    pub(crate) fn decay_with(&mut self, mode: DecayMode) {
+        self.apply_decay(mode, None);
+    }
+
+    pub(crate) fn decay_ticks(&mut self, mode: DecayMode, ticks: u64) {
+        // Each arm counts the non-zero signals it leaves
+        let scale = |signals: &mut [Signal], retention: u8| {
+            let factor = retention_power_q32(retention, ticks);
+            if factor == 0 {
+                signals.fill(Signal::ZERO);
+                return 0;
+            }
+            let mut nonzero = 0;
+            for s in signals {
+                let current = s.current();
+                if current != 0 {
+                    let magnitude = (current.unsigned_abs() as u128 * factor as u128) >> 32;
+                    *s = Signal::from_current(current.signum() * magnitude as i32);
+                }
+                nonzero += usize::from(s.magnitude > 0);
+            }
+            nonzero
+        };
+        let nonzero = match mode {
+            DecayMode::Multiplicative(retention) => scale(self.signals, retention),
+            DecayMode::Linear(step) => {
+                let step = (step as u64).saturating_mul(ticks).min(i32::MAX as u64) as i32;
+                let mut nonzero = 0;
+                for s in self.signals.iter_mut() {
+                    let current = s.current();
+                    let magnitude = (current.abs() - step).max(0);
+                    *s = Signal::from_current(current.signum() * magnitude);
+                    nonzero += usize::from(s.magnitude > 0);
+                }
+                nonzero
+            }
+            DecayMode::Floor {
+                retention,
+                min_magnitude,
+            } => {
+                scale(self.signals, retention);
+                floor(self.signals, min_magnitude)
+            }
+        };
+        self.set_nonzero(nonzero);
+    }
+
+    /// Decay under a mode, dithering multiplicative steps when given an RNG.
+    fn apply_decay(&mut self, mode: DecayMode, rng_state: Option<&mut u64>) {
+        let multiply = |v: &mut FrameMut<'_>, retention: u8| match rng_state {
+            Some(state) => v.decay_dithered(retention, state),
+            None => v.decay(retention),
+        };
+        match mode {
+            DecayMode::Multiplicative(retention) => multiply(self, retention),
+            DecayMode::Linear(step) => {
+                let mut nonzero = 0;
+                for s in self.signals.iter_mut() {
+                    let current = s.current();
+                    if current != 0 {
+                        let magnitude = (current.abs() - step as i32).max(0);
+                        *s = Signal::from_current(current.signum() * magnitude);
+                    }
+                    nonzero += usize::from(s.magnitude > 0);
+                }
+                self.set_nonzero(nonzero);
+            }
+            DecayMode::Floor {
+                retention,
+                min_magnitude,
+            } => {
+                multiply(self, retention);
+                let nonzero = floor(self.signals, min_magnitude);
+                self.set_nonzero(nonzero);
             }
         }
     }
 
-    /// Add another vector (saturating at ±65,025).
-    pub fn add(&mut self, other: &FieldVector) {
-        debug_assert_eq!(self.dims(), other.dims());
+    pub(crate) fn add(&mut self, other: FrameRef<'_>) {
+        debug_assert_eq!(self.signals.len(), other.dims());
         for i in 0..self.signals.len() {
             let a = self.get_current(i);
             let b = other.get_current(i);
@@ -116,11 +986,20 @@ impl FieldVector {
         }
     }
 
-    /// Add Signals to a range (saturating at ±65,025).
-    pub fn add_to_range(&mut self, signals: &[Signal], range: Range<usize>) {
-        let range_len = range.len();
-        for (i, &s) in signals.iter().take(range_len).enumerate() {
-            let idx = range.start + i;
+    pub(crate) fn merge_max(&mut self, other: FrameRef<'_>) {
+        for (i, &theirs) in other.signals.iter().enumerate().take(self.signals.len()) {
+            if theirs.effective_magnitude() > self.signals[i].effective_magnitude() {
+                self.put(i, theirs);
+            }
+        }
+    }
+
+    pub(crate) fn add_to_range(&mut self, signals: &[Signal], range: Range<usize>) {
+        self.add_to_indices(signals, &RegionSpec::Contiguous(range));
+    }
+
+    pub(crate) fn add_to_indices(&mut self, signals: &[Signal], spec: &RegionSpec) {
+        for (idx, &s) in spec.iter().zip(signals) {
             if idx < self.signals.len() {
                 let current = self.get_current(idx);
                 let delta = s.current();
@@ -130,73 +1009,265 @@ impl FieldVector {
         }
     }
 
-    /// Set Signals in a range.
-    pub fn set_range(&mut self, signals: &[Signal], range: Range<usize>) {
-        let range_len = range.len();
-        for (i, &s) in signals.iter().take(range_len).enumerate() {
-            let idx = range.start + i;
+    pub(crate) fn add_to_range_iter(
+        &mut self,
+        signals: impl IntoIterator<Item = Signal>,
+        range: Range<usize>,
+    ) {
+        for (idx, s) in range.zip(signals) {
             if idx < self.signals.len() {
-                self.signals[idx] = s;
+                let sum = (self.get_current(idx) as i64 + s.current() as i64).clamp(-65025, 65025);
+                self.set_current(idx, sum as i32);
             }
         }
     }
 
-    /// Get Signals from a range.
-    pub fn get_range(&self, range: Range<usize>) -> Vec<Signal> {
-        (range.start..range.end.min(self.dims()))
-            .map(|i| self.signals[i])
-            .collect()
+    pub(crate) fn set_range_iter(
+        &mut self,
+        signals: impl IntoIterator<Item = Signal>,
+        range: Range<usize>,
+    ) {
+        for (idx, s) in range.zip(signals) {
+            if idx < self.signals.len() {
+                self.put(idx, s);
+            }
+        }
     }
 
-    /// Compute energy (sum of squared effective magnitudes) in a range.
-    /// Returns u64 to prevent overflow (max per element: 65025² ≈ 4.2B).
-    pub fn range_energy(&self, range: Range<usize>) -> u64 {
-        (range.start..range.end.min(self.dims()))
-            .map(|i| {
-                let eff = self.signals[i].effective_magnitude() as u64;
-                eff * eff
-            })
-            .sum()
+    pub(crate) fn add_sparse(&mut self, entries: &[(usize, Signal)]) {
+        for &(idx, s) in entries {
+            if idx < self.signals.len() {
+                let sum = (self.get_current(idx) as i64 + s.current() as i64).clamp(-65025, 65025);
+                self.set_current(idx, sum as i32);
+            }
+        }
     }
 
-    /// Check if range is active (energy above threshold).
-    pub fn range_active(&self, range: Range<usize>, threshold: u64) -> bool {
-        self.range_energy(range) > threshold
+    pub(crate) fn set_range(&mut self, signals: &[Signal], range: Range<usize>) {
+        self.set_indices(signals, &RegionSpec::Contiguous(range));
     }
 
-    /// Check if all signals are zero.
-    pub fn is_zero(&self) -> bool {
-        self.signals.iter().all(|s| s.magnitude == 0)
+    pub(crate) fn set_indices(&mut self, signals: &[Signal], spec: &RegionSpec) {
+        for (idx, &s) in spec.iter().zip(signals) {
+            if idx < self.signals.len() {
+                self.put(idx, s);
+            }
+        }
     }
 
-    /// Count non-zero signals.
-    pub fn non_zero_count(&self) -> usize {
-        self.signals.iter().filter(|s| s.magnitude > 0).count()
+    pub(crate) fn add_upsampled(&mut self, signals: &[Signal], range: Range<usize>) {
+        if signals.is_empty() {
+            return;
+        }
+        let factor = range.len().div_ceil(signals.len());
+        let broadcast: Vec<Signal> = (0..range.len()).map(|j| signals[j / factor]).collect();
+        self.add_to_range(&broadcast, range);
     }
 
-    /// Get maximum effective magnitude.
-    pub fn max_magnitude(&self) -> u16 {
-        self.signals.iter().map(|s| s.effective_magnitude()).max().unwrap_or(0)
+    pub(crate) fn shift_range(&mut self, range: Range<usize>, offset: isize) {
+        self.rewrite_range(range, |slice| {
+            let len = slice.len();
+            let by = offset.unsigned_abs().min(len);
+            if offset >= 0 {
+                slice.rotate_right(by);
+                slice[..by].fill(Signal::ZERO);
+            } else {
+                slice.rotate_left(by);
+                slice[len - by..].fill(Signal::ZERO);
+            }
+        });
     }
 
-    /// Scale all values by factor (u8 where 255 = 1.0).
-    pub fn scale(&mut self, factor: u8) {
-        for s in &mut self.signals {
-            let current = s.current();
-            let scaled = (current as i64 * factor as i64 / 255) as i32;
-            *s = Signal::from_current(scaled);
+    pub(crate) fn rotate(&mut self, range: Range<usize>, k: usize) {
+        let dims = self.signals.len();
+        let range = range.start.min(dims)..range.end.min(dims);
+        if range.is_empty() {
+            return;
         }
+        let len = range.len();
+        self.signals[range].rotate_right(k % len);
     }
 
-    /// Get slice reference for direct access.
-    pub fn as_slice(&self) -> &[Signal] {
-        &self.signals
+    pub(crate) fn permute(&mut self, range: Range<usize>, perm: &[usize]) {
+        assert_eq!(
+            perm.len(),
+            range.len(),
+            "permutation of {} dims applied to {} dims",
+            perm.len(),
+            range.len()
+        );
+        let mut seen = vec![false; perm.len()];
+        for &p in perm {
+            assert!(
+                p < perm.len() && !core::mem::replace(&mut seen[p], true),
+                "not a permutation: {:?}",
+                perm
+            );
+        }
+        let original = self.signals[range.clone()].to_vec();
+        for (slot, &p) in self.signals[range].iter_mut().zip(perm) {
+            *slot = original[p];
+        }
     }
 
-    /// Get mutable slice reference.
-    pub fn as_mut_slice(&mut self) -> &mut [Signal] {
-        &mut self.signals
+    pub(crate) fn kwta(&mut self, range: Range<usize>, k: usize) {
+        let kept = self.frame().top_k(range.clone(), k);
+        let start = range.start;
+        self.rewrite_range(range, |slice| {
+            slice.fill(Signal::ZERO);
+            for (i, s) in kept {
+                slice[i - start] = s;
+            }
+        });
+    }
+
+    pub(crate) fn scale(&mut self, factor: u8) {
+        let nonzero = scale_scalar(self.signals, factor);
+        self.set_nonzero(nonzero);
+    }
+
+    /// Scale every value by `num / den`, rounding toward zero.
+    pub(crate) fn scale_ratio(&mut self, num: u32, den: u32) {
+        let mut nonzero = 0;
+        for s in self.signals.iter_mut() {
+            let scaled = s.current() as i64 * num as i64 / den as i64;
+            *s = Signal::from_current(scaled as i32);
+            nonzero += usize::from(s.magnitude > 0);
+        }
+        self.set_nonzero(nonzero);
+    }
+
+    pub(crate) fn scale_range(&mut self, range: Range<usize>, factor: u8) {
+        self.scale_indices(&RegionSpec::Contiguous(range), factor);
+    }
+
+    pub(crate) fn scale_indices(&mut self, spec: &RegionSpec, factor: u8) {
+        for i in spec.iter() {
+            if let Some(s) = self.signals.get(i) {
+                let scaled = (s.current() as i64 * factor as i64 / 255) as i32;
+                self.put(i, Signal::from_current(scaled));
+            }
+        }
+    }
+}
+
+/// Scalar [`FieldVector::decay`]; zero-current signals are left as they are.
+/// Returns the number of non-zero signals left.
+pub(crate) fn decay_scalar(signals: &mut [Signal], retention: u8) -> usize {
+    let mut nonzero = 0;
+    for s in signals {
+        let current = s.current();
+        if current != 0 {
+            // Apply retention to the effective value
+            let decayed = (current as i64 * retention as i64 / 255) as i32;
+            if decayed == 0 {
+                *s = Signal::ZERO;
+            } else {
+                *s = Signal::from_current(decayed);
+            }
+        }
+        nonzero += usize::from(s.magnitude > 0);
+    }
+    nonzero
+}
+
+/// Scalar [`FieldVector::scale`]. Returns the number of non-zero signals
+/// left.
+pub(crate) fn scale_scalar(signals: &mut [Signal], factor: u8) -> usize {
+    let mut nonzero = 0;
+    for s in signals {
+        let current = s.current();
+        let scaled = (current as i64 * factor as i64 / 255) as i32;
+        *s = Signal::from_current(scaled);
+        nonzero += usize::from(s.magnitude > 0);
+    }
+    nonzero
+}
+
+/// Zero signals whose effective magnitude is under `min_magnitude`
+/// ([`DecayMode::Floor`]). Returns the number of non-zero signals left.
+fn floor(signals: &mut [Signal], min_magnitude: u8) -> usize {
+    let mut nonzero = 0;
+    for s in signals {
+        if s.effective_magnitude() < min_magnitude as u16 {
+            *s = Signal::ZERO;
+        }
+        nonzero += usize::from(s.magnitude > 0);
+    }
+    nonzero
+}
+
+/// Σ(effective magnitude²) over a slice.
+fn energy_of(signals: &[Signal]) -> u64 {
+    signals
+        .iter()
+        .map(|s| {
+            let eff = s.effective_magnitude() as u64;
+            eff * eff
+        })
+        .sum()
+}
+
+/// `(retention / 255)^ticks` in 32-bit fixed point (1 << 32 = 1.0), by
+/// repeated squaring.
+fn retention_power_q32(retention: u8, mut ticks: u64) -> u64 {
+    const ONE: u64 = 1 << 32;
+    let mut base = ((retention as u64) << 32) / 255;
+    let mut result = ONE;
+    while ticks > 0 && result > 0 {
+        if ticks & 1 == 1 {
+            result = ((result as u128 * base as u128) >> 32) as u64;
+        }
+        base = ((base as u128 * base as u128) >> 32) as u64;
+        ticks >>= 1;
+    }
+    result
+}
+
+/// Seed used when a dither RNG state is zero (xorshift cannot leave zero).
+const DEFAULT_DITHER_SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Advance a xorshift64 state and return the new value.
+fn xorshift64(state: &mut u64) -> u64 {
+    let mut x = *state;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    *state = x;
+    x
+}
+
+/// Sign agreement and cosine, averaged per mille, over paired effective values.
+fn similarity_permille_of(pairs: impl Iterator<Item = (i32, i32)>) -> u16 {
+    let mut agree: u64 = 0;
+    let mut live: u64 = 0;
+    let mut dot: i128 = 0;
+    let mut norm_a: u128 = 0;
+    let mut norm_b: u128 = 0;
+    for (a, b) in pairs {
+        let (a, b) = (a as i128, b as i128);
+        if a != 0 || b != 0 {
+            live += 1;
+            if a.signum() == b.signum() {
+                agree += 1;
+            }
+        }
+        dot += a * b;
+        norm_a += (a * a) as u128;
+        norm_b += (b * b) as u128;
+    }
+    if live == 0 {
+        return 0;
     }
+
+    let sign_permille = agree * 1000 / live;
+    let norm = (norm_a * norm_b).isqrt();
+    let cos_permille = if dot > 0 && norm > 0 {
+        (dot as u128 * 1000 / norm).min(1000) as u64
+    } else {
+        0
+    };
+    ((sign_permille + cos_permille) / 2) as u16
 }
 
 impl Default for FieldVector {
@@ -219,8 +1290,8 @@ mod tests {
     #[test]
     fn test_set_get_signal() {
         let mut v = FieldVector::new(64);
-        v.set(0, Signal::positive(200));
-        v.set(10, Signal::negative(128));
+        v.set(0, Signal::positive_amplified(200, 1));
+        v.set(10, Signal::negative_amplified(128, 1));
 
         assert_eq!(v.get(0).polarity, 1);
         assert_eq!(v.get(0).magnitude, 200);
@@ -284,6 +1355,109 @@ mod tests {
         assert_eq!(energy, 100_000_000);
     }
 
+    #[test]
+    fn test_l1_and_signed_sum() {
+        let mut v = FieldVector::new(8);
+        v.set(0, Signal::positive_amplified(100, 2)); // 200
+        v.set(1, Signal::negative_amplified(150, 1)); // -150
+        v.set(2, Signal::negative_amplified(50, 1)); // -50
+
+        assert_eq!(v.range_l1(0..3), 400);
+        assert_eq!(v.range_signed_sum(0..3), 0);
+        assert_eq!(v.range_signed_sum(1..3), -200);
+        assert_eq!(v.indices_l1(&RegionSpec::from(vec![0, 2])), 250);
+        assert_eq!(v.range_l1(3..8), 0);
+    }
+
+    #[test]
+    fn test_centroid() {
+        let mut v = FieldVector::new(16);
+        assert_eq!(v.range_centroid(0..16), None);
+
+        v.set(5, Signal::positive_amplified(100, 1));
+        assert_eq!(v.range_centroid(0..16), Some(5));
+        assert_eq!(v.range_centroid(8..16), None);
+
+        // Polarity does not matter, only magnitude
+        v.set(9, Signal::negative_amplified(100, 1));
+        assert_eq!(v.range_centroid(0..16), Some(7));
+
+        // Asymmetric: (5 × 100 + 9 × 100 + 12 × 200) / 400 = 9.5 → 10
+        v.set(12, Signal::positive_amplified(200, 1));
+        assert_eq!(v.range_centroid(0..16), Some(10));
+        // Heavier tail pulls it toward 12: (9 × 100 + 12 × 300) / 400 = 11.25
+        v.set(12, Signal::positive_amplified(100, 3));
+        assert_eq!(v.range_centroid(8..16), Some(11));
+
+        let odd = RegionSpec::Strided {
+            start: 1,
+            step: 2,
+            count: 8,
+        };
+        // Only dims 5 and 9 are odd
+        assert_eq!(v.indices_centroid(&odd), Some(7));
+    }
+
+    #[test]
+    fn test_top_k() {
+        let mut v = FieldVector::new(16);
+        assert!(v.top_k(0..16, 4).is_empty());
+
+        v.set(2, Signal::positive_amplified(50, 1));
+        v.set(7, Signal::negative_amplified(200, 1));
+        v.set(9, Signal::positive_amplified(100, 1));
+        v.set(11, Signal::positive_amplified(100, 1));
+        v.set(14, Signal::positive_amplified(100, 2));
+
+        let top: Vec<usize> = v.top_k(0..16, 3).iter().map(|&(i, _)| i).collect();
+        assert_eq!(top, vec![7, 14, 9]); // 200 ties with 200: lower index first
+        assert_eq!(v.top_k(0..16, 3)[0].1.polarity, -1);
+
+        // k beyond the live dims returns them all, still sorted
+        let all: Vec<usize> = v.top_k(0..16, 100).iter().map(|&(i, _)| i).collect();
+        assert_eq!(all, vec![7, 14, 9, 11, 2]);
+        assert_eq!(v.top_k(8..12, 1)[0].0, 9);
+        assert!(v.top_k(0..16, 0).is_empty());
+        assert_eq!(v.top_k(12..100, 5).len(), 1);
+    }
+
+    #[test]
+    fn test_kwta() {
+        let mut v = FieldVector::new(12);
+        for i in 0..12 {
+            v.set_current(i, 100);
+        }
+        v.set(3, Signal::positive_amplified(100, 3));
+        v.set(9, Signal::negative_amplified(100, 2));
+
+        // 300 and 200 win, then ties at 100 keep the lowest index (2)
+        v.kwta(2..12, 3);
+        assert_eq!(v.range_occupancy(2..12), (3, 10));
+        assert_eq!(v.get_current(2), 100);
+        assert_eq!(v.get_current(3), 300);
+        assert_eq!(v.get_current(9), -200);
+        assert_eq!(v.get_current(4), 0);
+        // Outside the range is untouched
+        assert_eq!(v.get_current(0), 100);
+    }
+
+    #[test]
+    fn test_dot_range() {
+        let mut a = FieldVector::new(4);
+        let mut b = FieldVector::new(4);
+        a.set(0, Signal::positive_amplified(10, 1));
+        a.set(1, Signal::negative_amplified(20, 1));
+        a.set(3, Signal::positive_amplified(5, 9)); // multiplier ignored
+        b.set(0, Signal::positive_amplified(3, 1));
+        b.set(1, Signal::positive_amplified(4, 1));
+        b.set(3, Signal::positive_amplified(2, 1));
+
+        assert_eq!(a.dot_range(&b, 0..2), 30 - 80);
+        assert_eq!(a.dot_range(&b, 0..4), 30 - 80 + 10);
+        assert_eq!(a.dot_range(&b, 2..100), 10);
+        assert_eq!(a.dot_range(&a, 0..2), 500);
+    }
+
     #[test]
     fn test_add_to_range_full() {
         let mut v = FieldVector::new(64);
@@ -297,7 +1471,7 @@ mod tests {
     #[test]
     fn test_set_range() {
         let mut v = FieldVector::new(64);
-        let signals = vec![Signal::negative(50); 4];
+        let signals = vec![Signal::negative_amplified(50, 1); 4];
         v.set_range(&signals, 10..14);
 
         assert_eq!(v.get(9).magnitude, 0);
@@ -310,8 +1484,8 @@ mod tests {
     #[test]
     fn test_get_range() {
         let mut v = FieldVector::new(64);
-        v.set(5, Signal::positive(100));
-        v.set(6, Signal::negative(200));
+        v.set(5, Signal::positive_amplified(100, 1));
+        v.set(6, Signal::negative_amplified(200, 1));
 
         let range = v.get_range(5..7);
         assert_eq!(range.len(), 2);
@@ -355,4 +1529,327 @@ mod tests {
         assert_eq!(v.get_i16(0), 200);
         assert_eq!(v.get_i16(1), -128);
     }
+
+    #[test]
+    fn test_similarity_permille() {
+        let mut v = FieldVector::new(12);
+        for i in 0..4 {
+            v.set_current(i, 100 * (i as i32 + 1));
+            v.set_current(4 + i, 100 * (i as i32 + 1)); // echo of 0..4
+            v.set_current(8 + i, -100 * (i as i32 + 1)); // inverse of 0..4
+        }
+        assert_eq!(v.similarity_permille(0..4, 4..8), 1000);
+        assert_eq!(v.similarity_permille(0..4, 8..12), 0);
+
+        // Same signs, different shape: full sign agreement, partial cosine
+        v.set_current(4, 400);
+        v.set_current(7, 100);
+        let partial = v.similarity_permille(0..4, 4..8);
+        assert!(partial > 500 && partial < 1000, "got {}", partial);
+
+        assert_eq!(FieldVector::new(8).similarity_permille(0..4, 4..8), 0);
+    }
+
+    #[test]
+    fn test_downsample() {
+        let values = [10, -40, 30, 20, 5, 5, 100, -100, 60];
+        let mut v = FieldVector::new(values.len());
+        for (i, &value) in values.iter().enumerate() {
+            v.set_current(i, value);
+        }
+        let currents =
+            |signals: Vec<Signal>| signals.iter().map(|s| s.current()).collect::<Vec<_>>();
+
+        // 8 dims in bins of 2
+        assert_eq!(
+            currents(v.downsample(0..8, 2, PoolMode::Max)),
+            [-40, 30, 5, 100]
+        );
+        assert_eq!(
+            currents(v.downsample(0..8, 2, PoolMode::Mean)),
+            [-15, 25, 5, 0]
+        );
+        assert_eq!(currents(v.downsample(0..8, 4, PoolMode::Sum)), [20, 10]);
+
+        // 9 dims in bins of 4: the last bin holds dim 8 alone
+        assert_eq!(currents(v.downsample(0..9, 4, PoolMode::Mean)), [5, 2, 60]);
+        assert_eq!(v.downsample(0..9, 0, PoolMode::Max).len(), 9);
+    }
+
+    #[test]
+    fn test_upsample() {
+        let coarse = [
+            Signal::positive_amplified(50, 1),
+            Signal::negative_amplified(20, 1),
+        ];
+
+        let mut v = FieldVector::new(8);
+        v.add_upsampled(&coarse, 0..8);
+        let currents: Vec<i32> = (0..8).map(|i| v.get_current(i)).collect();
+        assert_eq!(currents, [50, 50, 50, 50, -20, -20, -20, -20]);
+
+        // Additive, and the round trip through mean pooling is exact
+        v.add_upsampled(&coarse, 0..8);
+        assert_eq!(v.get_current(0), 100);
+        let pooled = v.downsample(0..8, 4, PoolMode::Mean);
+        assert_eq!(pooled[1].current(), -40);
+
+        // 7 dims from 2 signals: bins of 4, the last signal covers 3
+        let mut v = FieldVector::new(7);
+        v.add_upsampled(&coarse, 0..7);
+        assert_eq!(v.get_current(3), 50);
+        assert_eq!(v.get_current(4), -20);
+        assert_eq!(v.get_current(6), -20);
+    }
+
+    #[test]
+    fn test_rotate_and_permute() {
+        let mut v = FieldVector::new(6);
+        for i in 0..6 {
+            v.set_current(i, i as i32 + 1);
+        }
+        let currents =
+            |v: &FieldVector| (0..v.dims()).map(|i| v.get_current(i)).collect::<Vec<_>>();
+
+        // Only dims 1..5 move
+        v.rotate(1..5, 1);
+        assert_eq!(currents(&v), [1, 5, 2, 3, 4, 6]);
+        v.rotate(1..5, 7); // 7 ≡ 3 (mod 4): back where we started
+        assert_eq!(currents(&v), [1, 2, 3, 4, 5, 6]);
+
+        v.permute(0..4, &[3, 0, 2, 1]);
+        assert_eq!(currents(&v), [4, 1, 3, 2, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a permutation")]
+    fn test_permute_rejects_repeats() {
+        FieldVector::new(4).permute(0..3, &[0, 0, 1]);
+    }
+
+    #[test]
+    fn test_bind() {
+        let mut v = FieldVector::new(6);
+        for (i, value) in [50, -80, 0, 30, -20, 10].into_iter().enumerate() {
+            v.set_current(i, value);
+        }
+        let bound: Vec<i32> = v.bind(0..3, 3..6).iter().map(|s| s.current()).collect();
+        assert_eq!(bound, [30, 20, 0]);
+    }
+
+    #[test]
+    fn test_bundle() {
+        let frames: Vec<FieldVector> = [[100, 100, -60], [80, -100, -60], [90, 10, -60]]
+            .iter()
+            .map(|values| {
+                let mut v = FieldVector::new(3);
+                for (i, &value) in values.iter().enumerate() {
+                    v.set_current(i, value);
+                }
+                v
+            })
+            .collect();
+        let refs: Vec<&FieldVector> = frames.iter().collect();
+
+        let bundled: Vec<i32> = FieldVector::bundle(&refs, 0..3)
+            .iter()
+            .map(|s| s.current())
+            .collect();
+        // Agreement keeps the mean; the conflicted dim cancels to near zero
+        assert_eq!(bundled, [90, 3, -60]);
+        assert_eq!(FieldVector::bundle(&refs[..2], 1..2), [Signal::ZERO]);
+        assert!(FieldVector::bundle(&[], 0..3).is_empty());
+    }
+
+    #[test]
+    fn test_shift_range() {
+        let mut v = FieldVector::new(6);
+        for i in 0..6 {
+            v.set_current(i, i as i32 + 1);
+        }
+        let currents =
+            |v: &FieldVector| (0..v.dims()).map(|i| v.get_current(i)).collect::<Vec<_>>();
+
+        v.shift_range(1..5, 2);
+        assert_eq!(currents(&v), [1, 0, 0, 2, 3, 6]);
+        v.shift_range(1..5, -1);
+        assert_eq!(currents(&v), [1, 0, 2, 3, 0, 6]);
+        v.shift_range(0..6, -10);
+        assert!(v.is_zero());
+    }
+
+    #[test]
+    fn test_decay_modes() {
+        let trajectory = |mode: DecayMode| {
+            let mut v = FieldVector::new(2);
+            v.set_current(0, 200);
+            v.set_current(1, -10);
+            (0..3)
+                .map(|_| {
+                    v.decay_with(mode);
+                    (v.get_current(0), v.get_current(1))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Large values drop fast, small ones linger
+        assert_eq!(
+            trajectory(DecayMode::Multiplicative(242)),
+            [(189, -9), (179, -8), (169, -7)]
+        );
+        // Same step for every dim, stopping at zero
+        assert_eq!(
+            trajectory(DecayMode::Linear(5)),
+            [(195, -5), (190, 0), (185, 0)]
+        );
+        // Multiplicative until the residue falls below the floor
+        assert_eq!(
+            trajectory(DecayMode::Floor {
+                retention: 242,
+                min_magnitude: 8,
+            }),
+            [(189, -9), (179, -8), (169, 0)]
+        );
+    }
+
+    #[test]
+    fn test_decay_dithered_is_unbiased() {
+        let mut rng = 42;
+        let trials = 10_000;
+        let mut sum = 0i64;
+        for _ in 0..trials {
+            let mut v = FieldVector::new(1);
+            v.set_current(0, 10);
+            v.decay_dithered(128, &mut rng);
+            sum += v.get_current(0) as i64;
+        }
+        // Ideal: 10 × 128 / 255 ≈ 5.02 (truncating decay gives exactly 5)
+        assert!((sum - 5 * trials).abs() <= trials, "mean {}", sum / trials);
+    }
+
+    #[test]
+    fn test_decay_dithered_has_no_fixed_points() {
+        let mut rng = 7;
+        let mut v = FieldVector::new(64);
+        for i in 0..64 {
+            v.set_current(i, (i as i32 + 1) * 1000 * if i % 2 == 0 { 1 } else { -1 });
+        }
+        for _ in 0..100 {
+            v.decay_dithered(200, &mut rng);
+        }
+        assert!(v.is_zero());
+
+        // A zero state still produces randomness
+        let mut zero = 0;
+        v.set_current(0, 100);
+        v.decay_dithered(254, &mut zero);
+        assert_ne!(zero, 0);
+    }
+
+    #[test]
+    fn test_range_iter_matches_slices() {
+        let signals: Vec<Signal> = (1..=6)
+            .map(|m| Signal::positive_amplified(m * 10, 1))
+            .collect();
+
+        let mut from_slice = FieldVector::new(8);
+        let mut from_iter = FieldVector::new(8);
+        from_slice.add_to_range(&signals, 2..8);
+        from_iter.add_to_range_iter(signals.iter().copied(), 2..8);
+        from_slice.set_range(&signals[..2], 0..2);
+        from_iter.set_range_iter(signals.iter().copied(), 0..2);
+        assert_eq!(from_slice.as_slice(), from_iter.as_slice());
+
+        // Extras are not consumed; a short iterator leaves the tail alone
+        let mut source = signals.iter().copied();
+        from_iter.add_to_range_iter(&mut source, 0..4);
+        assert_eq!(source.next(), Some(signals[4]));
+        from_iter.set_range_iter(core::iter::once(Signal::ZERO), 6..8);
+        assert_eq!(from_iter.get_current(6), 0);
+        assert_eq!(from_iter.get_current(7), 60);
+
+        // Out-of-bounds dims are ignored
+        from_iter.add_to_range_iter(signals.iter().copied(), 7..12);
+        assert_eq!(from_iter.get_current(7), 70);
+    }
+
+    #[test]
+    fn test_frame_ref_reads_like_vector() {
+        let mut v = FieldVector::new(8);
+        v.set_range(
+            &[
+                Signal::positive_amplified(90, 2),
+                Signal::negative_amplified(30, 1),
+            ],
+            2..4,
+        );
+        let frame = v.frame();
+
+        assert_eq!(frame.dims(), 8);
+        assert_eq!(frame.get_current(2), 180);
+        assert_eq!(frame.range_energy(0..8), v.range_energy(0..8));
+        assert_eq!(frame.top_k(0..8, 1), v.top_k(0..8, 1));
+        assert_eq!(frame.to_vector().as_slice(), v.as_slice());
+
+        // Derefs to the signals themselves
+        assert_eq!(frame.len(), 8);
+        assert_eq!(frame[3], Signal::negative_amplified(30, 1));
+    }
+
+    #[test]
+    fn test_tracked_count_matches_recount() {
+        let mut rng = 0x9e37_79b9_7f4a_7c15u64;
+        let mut signals = vec![Signal::ZERO; 48];
+        let mut nonzero = 0;
+        let other = FieldVector::from_signals(
+            (0..48)
+                .map(|i| Signal::from_current(i % 7 * 40 - 120))
+                .collect(),
+        );
+        for step in 0..2000 {
+            let r = xorshift64(&mut rng);
+            // Raw signals, including zero-valued ones with magnitude > 0
+            let random: Vec<Signal> = (0..12)
+                .map(|i| Signal {
+                    polarity: (r >> i) as i8 % 2,
+                    magnitude: (r >> (i * 4)) as u8 % 4 * 60,
+                    multiplier: (r >> (i * 3)) as u8 % 3,
+                })
+                .collect();
+            let at = (r >> 8) as usize % 48;
+            let range = at..at + (r >> 16) as usize % 12;
+            let spec = RegionSpec::Indices(vec![at, (at * 7) % 48, (at + 13) % 48]);
+            let mut frame = FrameMut::tracked(&mut signals, &mut nonzero);
+            match step % 19 {
+                0 => frame.set_current(at, (r >> 20) as i32 % 300 - 150),
+                1 => frame.set_i16(at, (r >> 20) as i16 % 3),
+                2 => frame.add_to_range(&random, range),
+                3 => frame.set_range(&random, range),
+                4 => frame.add_to_indices(&random, &spec),
+                5 => frame.set_indices(&random, &spec),
+                6 => frame.add_sparse(&[(at, random[0]), (60, random[1])]),
+                7 => frame.add_to_range_iter(random.iter().copied(), range),
+                8 => frame.set_range_iter(random.iter().copied(), range),
+                9 => frame.shift_range(range, (r >> 24) as isize % 7 - 3),
+                10 => frame.kwta(range, (r >> 24) as usize % 4),
+                11 => frame.scale_range(range, (r >> 24) as u8),
+                12 => frame.scale((r >> 24) as u8 | 128),
+                13 => frame.decay_with(DecayMode::Floor {
+                    retention: 240,
+                    min_magnitude: 20,
+                }),
+                14 => frame.decay_ticks(DecayMode::Linear(9), 3),
+                15 => frame.add_upsampled(&random[..3], range),
+                16 => frame.merge_max(other.frame()),
+                17 if r.is_multiple_of(5) => frame.clear(),
+                17 => frame.copy_from(other.frame()),
+                _ => frame.rotate(range, 2),
+            }
+            assert_eq!(
+                nonzero,
+                FrameRef::new(&signals).non_zero_count(),
+                "step {step}"
+            );
+        }
+    }
 }
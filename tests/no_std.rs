@@ -0,0 +1,50 @@
+//! Write, tick and observe using only `core` and `alloc`.
+//!
+//! `cargo test --no-default-features` runs this against the crate as
+//! embedded targets build it; the test itself never reaches for `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use temporal_field::{
+    EventMask, FieldConfig, FieldEvent, FnMutObserver, FnObserver, MonitoredRegion,
+    RecordingObserver, Signal, TemporalField,
+};
+
+#[test]
+fn test_write_tick_observe() {
+    let mut field = TemporalField::new(FieldConfig::new(16, 8, 128));
+    field.set_event_log_capacity(8);
+    field
+        .monitor_region(MonitoredRegion::new("input", 0..8, 10_000))
+        .unwrap();
+
+    let recorder = Arc::new(RecordingObserver::new());
+    field.subscribe(recorder.clone());
+    let active = Arc::new(AtomicUsize::new(0));
+    let seen = active.clone();
+    field.subscribe(Arc::new(FnObserver(move |event| {
+        if let FieldEvent::RegionActive { .. } = event {
+            seen.fetch_add(1, Ordering::Relaxed);
+        }
+    })));
+    let mut ticks = Vec::new();
+    field.subscribe(Arc::new(FnMutObserver::new(move |event: &FieldEvent| {
+        ticks.push(event.tick());
+    })));
+
+    field.write_region(&[Signal::positive_amplified(100, 1); 8], 0..8);
+    assert_eq!(field.read_region(0..8)[0].magnitude, 100);
+    assert_eq!(active.load(Ordering::Relaxed), 1);
+
+    // Decays by half each tick until the region goes quiet
+    field.tick();
+    assert_eq!(field.read_region(0..8)[0].magnitude, 50);
+    field.tick_n(4);
+    assert_eq!(recorder.count_of(&EventMask::REGION_QUIET), 1);
+    assert_eq!(field.drain_events().len(), recorder.len());
+}